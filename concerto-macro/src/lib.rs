@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{Expr, ExprLit, ExprPath, ItemFn, ItemImpl, ImplItem, Lit, Meta, MetaNameValue};
 
 /// Helper to create compile_error! token stream
@@ -8,10 +8,118 @@ fn compile_error(message: &str) -> TokenStream {
     error.to_compile_error().into()
 }
 
+/// Check if a value contains an unescaped config placeholder (`${...}`)
+fn has_placeholder(value: &str) -> bool {
+    value
+        .match_indices("${")
+        .any(|(index, _)| !value[..index].ends_with('\\'))
+}
+
+/// Scan a value with the runtime placeholder grammar
+///
+/// Supports `${key}`, `${key:default}` (defaults may contain placeholders),
+/// `${env:VAR}` and `\${` escapes. Returns the literal text outside placeholders,
+/// or an error message for unclosed or empty placeholders.
+fn scan_placeholders(value: &str) -> Result<String, String> {
+    let mut literal = String::new();
+    let mut i = 0;
+
+    while i < value.len() {
+        let rest = &value[i..];
+        if rest.starts_with("\\${") {
+            literal.push_str("${");
+            i += 3;
+        } else if rest.starts_with("${") {
+            // Bytes, not `&str` slices: `j` may fall inside a multi-byte character
+            let bytes = value.as_bytes();
+            let mut depth = 1;
+            let mut j = i + 2;
+            while j < bytes.len() && depth > 0 {
+                if bytes[j..].starts_with(b"\\${") {
+                    j += 3;
+                } else if bytes[j..].starts_with(b"${") {
+                    depth += 1;
+                    j += 2;
+                } else {
+                    if bytes[j] == b'}' {
+                        depth -= 1;
+                    }
+                    j += 1;
+                }
+            }
+            if depth > 0 {
+                return Err("Missing closing brace '}'.".to_string());
+            }
+
+            let inner = &value[i + 2..j - 1];
+            let body = inner.strip_prefix("env:").unwrap_or(inner);
+            let (key, default_value) = match body.find(':') {
+                Some(colon_pos) => (&body[..colon_pos], Some(&body[colon_pos + 1..])),
+                None => (body, None),
+            };
+            if key.trim().is_empty() {
+                return Err(format!("Placeholder '${{{}}}' has an empty key.", inner));
+            }
+            if let Some(default_value) = default_value {
+                scan_placeholders(default_value)?;
+            }
+            i = j;
+        } else {
+            let c = rest.chars().next().unwrap_or_default();
+            literal.push(c);
+            i += c.len_utf8();
+        }
+    }
+
+    Ok(literal)
+}
+
+/// Zone of a task as the runtime reads it
+#[derive(Debug, PartialEq)]
+enum ZoneName {
+    Local,
+    Utc,
+    Iana,
+}
+
+/// Parse `local`, `UTC` (case-insensitive) or an IANA name like `Asia/Jakarta`,
+/// with the same rules as the runtime
+fn parse_zone(zone: &str) -> Result<ZoneName, String> {
+    if zone.eq_ignore_ascii_case("local") {
+        Ok(ZoneName::Local)
+    } else if zone.eq_ignore_ascii_case("utc") {
+        Ok(ZoneName::Utc)
+    } else {
+        zone.parse::<chrono_tz::Tz>()
+            .map(|_| ZoneName::Iana)
+            .map_err(|_| format!(
+                "Invalid zone '{}'. Use 'local', 'UTC' or an IANA time zone name (e.g., 'Asia/Jakarta').",
+                zone
+            ))
+    }
+}
+
+/// Validate placeholder syntax only (unclosed braces, empty keys)
+/// Returns Some(error_message) if invalid, None if valid
+fn validate_placeholder_syntax(value: &str, field_name: &str, task_name: &str) -> Option<String> {
+    scan_placeholders(value).err().map(|reason| format!(
+        "Malformed config placeholder '{}' in {} for task '{}'.\n\
+         \n\
+         {}\n\
+         \n\
+         Examples:\n\
+         - ${{app.interval:5000}}            (with default value)\n\
+         - ${{app.interval:${{app.default:5s}}}}  (nested default)\n\
+         - ${{env:POLL_INTERVAL:30s}}        (environment variable)\n\
+         - \\${{literal}}                     (escaped, not a placeholder)",
+        value, field_name, task_name, reason
+    ))
+}
+
 /// Check if a value string has a time unit suffix (e.g., "5s", "10m")
 fn has_time_suffix(value: &str) -> bool {
     // Check for config placeholder
-    if value.starts_with("${") {
+    if has_placeholder(value) {
         return false;
     }
     
@@ -27,7 +135,7 @@ fn has_time_suffix(value: &str) -> bool {
 /// Validate time suffix format (must be lowercase)
 /// Returns Some(error_message) if invalid, None if valid
 fn validate_time_suffix(value: &str, field_name: &str, task_name: &str) -> Option<String> {
-    if value.starts_with("${") {
+    if has_placeholder(value) {
         return None; // Skip config placeholders
     }
     
@@ -93,90 +201,46 @@ fn validate_time_suffix(value: &str, field_name: &str, task_name: &str) -> Optio
 /// Validate config placeholder format and detect malformed patterns
 /// Returns Some(error_message) if invalid, None if valid
 fn validate_config_placeholder_format(value: &str, field_name: &str, task_name: &str) -> Option<String> {
-    let trimmed = value.trim();
-    
-    // Pattern 1: Detect incomplete placeholder like "${xxx" (missing closing brace)
-    if trimmed.starts_with("${") && !trimmed.ends_with("}") {
-        return Some(format!(
-            "Malformed config placeholder '{}' in {} for task '{}'.\n\
-             \n\
-             Missing closing brace '}}'.\n\
-             \n\
-             Correct format:\n\
-             {} = \"${{config.key:default}}\"\n\
-             \n\
-             Examples:\n\
-             - ${{app.interval:5000}}       (with default value)\n\
-             - ${{app.enabled:true}}        (boolean config)",
-            value, field_name, task_name, field_name
-        ));
+    if let Some(err) = validate_placeholder_syntax(value, field_name, task_name) {
+        return Some(err);
     }
-    
-    // Pattern 2: Detect mix of config + suffix like "${xxx}s" or "${xxx}ms"
-    if trimmed.contains("${") && trimmed.contains("}") {
-        let after_closing = trimmed.find("}").map(|pos| &trimmed[pos+1..]).unwrap_or("");
-        if !after_closing.is_empty() && after_closing.trim() != "" {
-            return Some(format!(
-                "Invalid format '{}' in {} for task '{}'.\n\
-                 \n\
-                 Cannot mix config placeholder with time suffix.\n\
-                 \n\
-                 ❌ WRONG:\n\
-                 {} = \"${{app.interval}}s\"     (config + suffix)\n\
-                 {} = \"${{app.interval}}ms\"    (config + suffix)\n\
-                 {} = \"${{app.interval}}sec\"   (config + suffix)\n\
-                 \n\
-                 ✅ CORRECT - Option 1 (config with default):\n\
-                 {} = \"${{app.interval:5}}\", time_unit = TimeUnit::Seconds\n\
-                 \n\
-                 ✅ CORRECT - Option 2 (config returns value with suffix):\n\
-                 {} = \"${{app.interval:5s}}\"   (config value already has suffix)\n\
-                 \n\
-                 ✅ CORRECT - Option 3 (hardcoded with suffix):\n\
-                 {} = \"5s\"                     (no config)",
-                value, field_name, task_name,
-                field_name, field_name, field_name,
-                field_name,
-                field_name,
-                field_name
-            ));
-        }
+    if !has_placeholder(value) {
+        return None;
     }
-    
-    // Pattern 3: Detect random characters after closing brace (not valid suffixes)
-    if trimmed.starts_with("${") && trimmed.contains("}") {
-        if let Some(pos) = trimmed.find("}") {
-            let after = &trimmed[pos+1..];
-            // If there's content after }, it must be empty or whitespace
-            if !after.trim().is_empty() {
-                // Check if it looks like someone tried to add suffix
-                if after.chars().any(|c| c.is_alphabetic()) {
-                    return Some(format!(
-                        "Invalid format '{}' in {} for task '{}'.\n\
-                         \n\
-                         Extra characters '{}' found after config placeholder.\n\
-                         \n\
-                         Cannot append time suffix to config placeholder.\n\
-                         \n\
-                         ✅ CORRECT:\n\
-                         {} = \"${{app.interval:5000}}\"   (milliseconds by default)\n\
-                         {} = \"${{app.interval:5s}}\"     (config value has suffix)\n\
-                         {} = \"${{app.interval:5}}\", time_unit = TimeUnit::Seconds",
-                        value, field_name, task_name, after,
-                        field_name, field_name, field_name
-                    ));
-                }
-            }
-        }
+
+    // Text around placeholders may only be digits and a time suffix (e.g. "${app.interval}s")
+    let literal = scan_placeholders(value).unwrap_or_default();
+    let literal = literal.trim();
+    let without_suffix = ["ms", "s", "m", "h", "d"]
+        .iter()
+        .find_map(|suffix| literal.strip_suffix(suffix))
+        .unwrap_or(literal);
+
+    if without_suffix.chars().all(|c| c.is_ascii_digit()) {
+        return None;
     }
-    
-    None
+
+    Some(format!(
+        "Invalid format '{}' in {} for task '{}'.\n\
+         \n\
+         Extra characters '{}' found around config placeholder.\n\
+         \n\
+         Only a lowercase time suffix ('ms', 's', 'm', 'h', 'd') may follow a placeholder.\n\
+         \n\
+         ✅ CORRECT:\n\
+         {} = \"${{app.interval:5000}}\"   (milliseconds by default)\n\
+         {} = \"${{app.interval:5s}}\"     (config value has suffix)\n\
+         {} = \"${{app.interval}}s\"       (config value + suffix)\n\
+         {} = \"${{app.interval:5}}\", time_unit = TimeUnit::Seconds",
+        value, field_name, task_name, literal,
+        field_name, field_name, field_name, field_name
+    ))
 }
 
 /// Validate that value is not negative or zero (for intervals)
 /// Returns Some(error_message) if invalid, None if valid
 fn validate_positive_value(value: &str, field_name: &str, task_name: &str, allow_zero: bool) -> Option<String> {
-    if value.starts_with("${") {
+    if has_placeholder(value) {
         return None; // Skip config placeholders (will be validated at runtime)
     }
     
@@ -212,6 +276,54 @@ fn validate_positive_value(value: &str, field_name: &str, task_name: &str, allow
     None
 }
 
+/// Validate a literal `enabled` value
+/// Plain values (no placeholders or operators) must be `true` or `false`
+/// Returns Some(error_message) if invalid, None if valid
+fn validate_enabled_value(value: &str, task_name: &str) -> Option<String> {
+    let trimmed = value.trim();
+    let is_expression = trimmed.contains("${")
+        || trimmed.contains("&&")
+        || trimmed.contains("||")
+        || trimmed.contains('!')
+        || trimmed.contains("==");
+
+    if is_expression || matches!(trimmed.to_lowercase().as_str(), "true" | "false") {
+        return None;
+    }
+
+    Some(format!(
+        "Invalid enabled value '{}' for task '{}'.\n\
+         \n\
+         enabled must be a boolean, a config placeholder or a boolean expression.\n\
+         \n\
+         Examples:\n\
+         - enabled = true\n\
+         - enabled = \"${{feature.sync:true}}\"\n\
+         - enabled = \"${{feature.sync}} && ${{region}} == 'eu'\"",
+        value, task_name
+    ))
+}
+
+/// Parse an array of string literals like `["billing", "worker"]`
+fn parse_string_array(value: &Expr, field_name: &str) -> Result<Vec<String>, String> {
+    let invalid = || format!(
+        "{} must be an array of string literals (e.g., {} = [\"billing\", \"worker\"])",
+        field_name, field_name
+    );
+
+    match value {
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .map(|elem| match elem {
+                Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(s.value()),
+                _ => Err(invalid()),
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+
 /// Scheduled task macro that supports Spring Boot-like scheduling
 /// 
 /// This macro works with standalone functions, Runnable trait implementations, and impl blocks with methods.
@@ -310,9 +422,21 @@ fn validate_positive_value(value: &str, field_name: &str, task_name: &str, allow
 /// - `fixed_rate`: Fixed interval between task executions
 /// - `fixed_delay`: Fixed delay between task completions
 /// - `time_unit`: Time unit (milliseconds, seconds, minutes, hours, days)
-/// - `zone`: Timezone for cron expressions (e.g., "Asia/Jakarta", "UTC")
+/// - `zone`: Timezone for cron expressions (e.g., "Asia/Jakarta", "local"), UTC by default
 /// - `initial_delay`: Delay before first execution
-/// - `enabled`: Enable/disable task (boolean or config placeholder)
+/// - `warn_after`: Running time after which a run is reported as stuck (e.g., `warn_after = "5m"`)
+/// - `expect_success_within`: Report the task as overdue when no run succeeded for this long
+///   (e.g., `expect_success_within = "26h"`)
+/// - `timeout`: Running time after which a run is cancelled and reported as timed out
+///   (e.g., `timeout = "30s"`)
+/// - `enabled`: Enable/disable task (boolean, config placeholder or boolean expression
+///   like `"${feature.sync} && ${region} == 'eu'"`)
+/// - `condition`: Predicate `fn(&Config) -> bool` evaluated at start (e.g., `condition = checks::is_eu`)
+/// - `name`: Explicit task name (defaults to the function name or `Type::method`)
+/// - `tags`: Labels for selecting tasks at build time (e.g., `tags = ["billing", "worker"]`)
+/// - `profiles`: Profiles in which the task is active (e.g., `profiles = ["prod", "staging"]`)
+/// - `description`: Human readable description of the task
+/// - `owner`: Team or person responsible for the task
 #[proc_macro_attribute]
 pub fn scheduled(args: TokenStream, input: TokenStream) -> TokenStream {
     // Try to parse as function first
@@ -360,9 +484,25 @@ fn handle_scheduled_function(args: TokenStream, input_fn: ItemFn) -> TokenStream
     let schedule_type = parsed_attrs.schedule_type;
     let schedule_value = parsed_attrs.schedule_value;
     let initial_delay_str = parsed_attrs.initial_delay;
+    let warn_after_str = parsed_attrs.warn_after;
+    let expect_success_within_str = parsed_attrs.expect_success_within;
+    let timeout_str = parsed_attrs.timeout;
     let enabled_str = parsed_attrs.enabled;
+    let task_name = match &parsed_attrs.name {
+        Some(name) => quote! { #name },
+        None => quote! { stringify!(#fn_name) },
+    };
+    let location = quote_spanned! { fn_name.span() => file!(), line!() };
+    let condition_call = match &parsed_attrs.condition {
+        Some(path) => quote! { .condition(#path) },
+        None => quote! {},
+    };
     let time_unit_str = parsed_attrs.time_unit;
     let zone_str = parsed_attrs.zone;
+    let tags = parsed_attrs.tags;
+    let profiles = parsed_attrs.profiles;
+    let description = parsed_attrs.description;
+    let owner = parsed_attrs.owner;
     let time_unit_path = parsed_attrs.time_unit_path;
 
     // Generate unique registration function name
@@ -397,19 +537,28 @@ fn handle_scheduled_function(args: TokenStream, input_fn: ItemFn) -> TokenStream
         #[linkme(crate = ::concerto::concerto_runtime::linkme)]
         fn #register_fn_name() -> ::concerto::concerto_runtime::ScheduledTask {
             ::concerto::concerto_runtime::ScheduledTask::builder(
-                stringify!(#fn_name),
+                #task_name,
                 || {
-                    ::tokio::spawn(async {
-                        #fn_name().await;
-                    });
+                    ::std::boxed::Box::pin(async {
+                        ::concerto::concerto_runtime::IntoTaskResult::into_task_result(#fn_name().await)
+                    })
                 }
             )
             .schedule_type(#schedule_type)
             .schedule_value(#schedule_value)
             .initial_delay(#initial_delay_str)
+            .warn_after(#warn_after_str)
+            .expect_success_within(#expect_success_within_str)
+            .timeout(#timeout_str)
             .enabled(#enabled_str)
+            #condition_call
             .time_unit(#time_unit_str)
             .zone(#zone_str)
+            .tags(&[#(#tags),*])
+            .profiles(&[#(#profiles),*])
+            .description(#description)
+            .owner(#owner)
+            .location(#location)
             .build()
         }
     };
@@ -503,9 +652,20 @@ fn handle_scheduled_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStream
     let schedule_type = parsed_attrs.schedule_type;
     let schedule_value = parsed_attrs.schedule_value;
     let initial_delay_str = parsed_attrs.initial_delay;
+    let warn_after_str = parsed_attrs.warn_after;
+    let expect_success_within_str = parsed_attrs.expect_success_within;
+    let timeout_str = parsed_attrs.timeout;
     let enabled_str = parsed_attrs.enabled;
+    let task_name = name_tokens(&parsed_attrs.name);
+    let location_file = quote_spanned! { input_impl.impl_token.span => file!() };
+    let location_line = quote_spanned! { input_impl.impl_token.span => line!() };
+    let condition = condition_tokens(&parsed_attrs.condition);
     let time_unit_str = parsed_attrs.time_unit;
     let zone_str = parsed_attrs.zone;
+    let tags = parsed_attrs.tags;
+    let profiles = parsed_attrs.profiles;
+    let description = parsed_attrs.description;
+    let owner = parsed_attrs.owner;
     let time_unit_path = parsed_attrs.time_unit_path;
     
     // Generate time_unit_enum() implementation if TimeUnit was explicitly specified
@@ -535,6 +695,12 @@ fn handle_scheduled_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStream
             #[doc(hidden)]
             pub const __INITIAL_DELAY: &'static str = #initial_delay_str;
             #[doc(hidden)]
+            pub const __WARN_AFTER: &'static str = #warn_after_str;
+            #[doc(hidden)]
+            pub const __EXPECT_SUCCESS_WITHIN: &'static str = #expect_success_within_str;
+            #[doc(hidden)]
+            pub const __TIMEOUT: &'static str = #timeout_str;
+            #[doc(hidden)]
             pub const __ENABLED: &'static str = #enabled_str;
             #[doc(hidden)]
             pub const __TIME_UNIT: &'static str = #time_unit_str;
@@ -547,9 +713,18 @@ fn handle_scheduled_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStream
             fn schedule_type() -> &'static str { #schedule_type }
             fn schedule_value() -> &'static str { #schedule_value }
             fn initial_delay() -> &'static str { #initial_delay_str }
+            fn warn_after() -> &'static str { #warn_after_str }
+            fn expect_success_within() -> &'static str { #expect_success_within_str }
+            fn timeout() -> &'static str { #timeout_str }
             fn enabled() -> &'static str { #enabled_str }
+            fn condition() -> Option<::concerto::concerto_runtime::TaskCondition> { #condition }
             fn time_unit() -> &'static str { #time_unit_str }
             fn zone() -> &'static str { #zone_str }
+            fn name() -> Option<&'static str> { #task_name }
+            fn tags() -> &'static [&'static str] { &[#(#tags),*] }
+            fn profiles() -> &'static [&'static str] { &[#(#profiles),*] }
+            fn description() -> &'static str { #description }
+            fn owner() -> &'static str { #owner }
             
             #time_unit_enum_impl
         }
@@ -562,21 +737,33 @@ fn handle_scheduled_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStream
                 vec![
                     ::concerto::concerto_runtime::ScheduledMethodMetadata {
                         method_name: "run",
+                        name: #task_name,
                         schedule_type: #schedule_type,
                         schedule_value: #schedule_value,
                         initial_delay: #initial_delay_str,
+                        warn_after: #warn_after_str,
+                        expect_success_within: #expect_success_within_str,
+                        timeout: #timeout_str,
                         enabled: #enabled_str,
+                        condition: #condition,
                         time_unit: #time_unit_str,
                         zone: #zone_str,
+                        tags: &[#(#tags),*],
+                        profiles: &[#(#profiles),*],
+                        description: #description,
+                        owner: #owner,
+                        file: #location_file,
+                        line: #location_line,
                     }
                 ]
             }
 
-            fn call_scheduled_method(&self, _method_name: &str) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = ()> + Send + '_>> {
+            fn call_scheduled_method(&self, _method_name: &str) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = ::concerto::concerto_runtime::TaskResult> + Send + '_>> {
                 // Call the run() method from Runnable trait (now synchronous)
                 // Wrap it in a future for compatibility with the scheduler
                 ::std::boxed::Box::pin(async move {
                     <Self as ::concerto::concerto_runtime::Runnable>::run(self);
+                    Ok(())
                 })
             }
         }
@@ -602,25 +789,16 @@ fn handle_impl_with_scheduled_methods(_args: TokenStream, mut input_impl: ItemIm
                 let method_name = &method.sig.ident;
                 let method_name_str = method_name.to_string();
 
-                // Parse the attribute arguments
-                let attr_meta: Meta = attr.parse_args().expect("Failed to parse scheduled attribute");
-                let mut attr_args = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::new();
-                
-                // Handle the meta
-                match attr_meta {
-                    Meta::List(list) => {
-                        // Parse the nested metas
-                        attr_args = list.parse_args_with(
-                            syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated
-                        ).expect("Failed to parse attribute arguments");
-                    }
-                    Meta::NameValue(nv) => {
-                        attr_args.push(Meta::NameValue(nv));
-                    }
-                    Meta::Path(_) => {
-                        // No arguments
-                    }
-                }
+                // Parse the attribute arguments (e.g., fixed_rate = "5s", tags = ["worker"])
+                let attr_args = match attr.parse_args_with(
+                    syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated
+                ) {
+                    Ok(args) => args,
+                    Err(e) => return compile_error(&format!(
+                        "Failed to parse #[scheduled] arguments on method '{}': {}",
+                        method_name_str, e
+                    )),
+                };
 
                 let parsed_attrs = match parse_schedule_args(&attr_args, &method_name_str) {
                     Ok(args) => args,
@@ -630,24 +808,48 @@ fn handle_impl_with_scheduled_methods(_args: TokenStream, mut input_impl: ItemIm
                 let schedule_type = parsed_attrs.schedule_type;
                 let schedule_value = parsed_attrs.schedule_value;
                 let initial_delay_str = parsed_attrs.initial_delay;
+                let warn_after_str = parsed_attrs.warn_after;
+                let expect_success_within_str = parsed_attrs.expect_success_within;
+                let timeout_str = parsed_attrs.timeout;
                 let enabled_str = parsed_attrs.enabled;
+                let task_name = name_tokens(&parsed_attrs.name);
+                let location_file = quote_spanned! { method_name.span() => file!() };
+                let location_line = quote_spanned! { method_name.span() => line!() };
+                let condition = condition_tokens(&parsed_attrs.condition);
                 let time_unit_str = parsed_attrs.time_unit;
                 let zone_str = parsed_attrs.zone;
+                let tags = parsed_attrs.tags;
+                let profiles = parsed_attrs.profiles;
+                let description = parsed_attrs.description;
+                let owner = parsed_attrs.owner;
 
                 scheduled_methods.push(quote! {
                     ::concerto::concerto_runtime::ScheduledMethodMetadata {
                         method_name: stringify!(#method_name),
+                        name: #task_name,
                         schedule_type: #schedule_type,
                         schedule_value: #schedule_value,
                         initial_delay: #initial_delay_str,
+                        warn_after: #warn_after_str,
+                        expect_success_within: #expect_success_within_str,
+                        timeout: #timeout_str,
                         enabled: #enabled_str,
+                        condition: #condition,
                         time_unit: #time_unit_str,
                         zone: #zone_str,
+                        tags: &[#(#tags),*],
+                        profiles: &[#(#profiles),*],
+                        description: #description,
+                        owner: #owner,
+                        file: #location_file,
+                        line: #location_line,
                     }
                 });
 
                 method_calls.push(quote! {
-                    stringify!(#method_name) => ::std::boxed::Box::pin(self.#method_name())
+                    stringify!(#method_name) => ::std::boxed::Box::pin(async move {
+                        ::concerto::concerto_runtime::IntoTaskResult::into_task_result(self.#method_name().await)
+                    })
                 });
             }
         }
@@ -663,10 +865,10 @@ fn handle_impl_with_scheduled_methods(_args: TokenStream, mut input_impl: ItemIm
                 ]
             }
 
-            fn call_scheduled_method(&self, method_name: &str) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = ()> + Send + '_>> {
+            fn call_scheduled_method(&self, method_name: &str) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = ::concerto::concerto_runtime::TaskResult> + Send + '_>> {
                 match method_name {
                     #(#method_calls,)*
-                    _ => ::std::boxed::Box::pin(async {}),
+                    _ => ::std::boxed::Box::pin(async { Ok(()) }),
                 }
             }
         }
@@ -683,14 +885,39 @@ pub fn __scheduled_method(_args: TokenStream, input: TokenStream) -> TokenStream
     input
 }
 
+/// Render the explicit task name as an `Option<&'static str>` expression
+fn name_tokens(name: &Option<String>) -> proc_macro2::TokenStream {
+    match name {
+        Some(name) => quote! { Some(#name) },
+        None => quote! { None },
+    }
+}
+
+/// Render the condition as an `Option<TaskCondition>` expression
+fn condition_tokens(condition: &Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    match condition {
+        Some(path) => quote! { Some(#path as ::concerto::concerto_runtime::TaskCondition) },
+        None => quote! { None },
+    }
+}
+
 /// Parsed schedule attributes
 struct ParsedScheduleAttrs {
     schedule_type: String,
     schedule_value: String,
     initial_delay: String,
+    warn_after: String,
+    expect_success_within: String,
+    timeout: String,
     enabled: String,
+    condition: Option<proc_macro2::TokenStream>,
     time_unit: String,
     zone: String,
+    name: Option<String>,
+    tags: Vec<String>,
+    profiles: Vec<String>,
+    description: String,
+    owner: String,
     time_unit_path: Option<proc_macro2::TokenStream>,
 }
 
@@ -701,11 +928,20 @@ fn parse_schedule_args(
     let mut schedule_type = None;
     let mut schedule_value = None;
     let mut initial_delay = None;
+    let mut warn_after = None;
+    let mut expect_success_within = None;
+    let mut timeout = None;
     let mut enabled = None;
+    let mut condition: Option<proc_macro2::TokenStream> = None;
     let mut time_unit = None;
     let mut time_unit_path: Option<proc_macro2::TokenStream> = None; // Store the actual TimeUnit:: path
     let mut time_unit_display: Option<String> = None; // Store display string for warnings (e.g., "TimeUnit::Minutes")
     let mut zone = None;
+    let mut tags = Vec::new();
+    let mut profiles = Vec::new();
+    let mut name = None;
+    let mut description = None;
+    let mut owner = None;
 
     // Parse macro arguments using syn 2.0 API
     for arg in attr_args {
@@ -718,6 +954,9 @@ fn parse_schedule_args(
             match path_str.as_str() {
                 "cron" => {
                     if let Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) = value {
+                        if let Some(err) = validate_placeholder_syntax(&s.value(), "cron", task_name) {
+                            return Err(err);
+                        }
                         schedule_type = Some("cron");
                         schedule_value = Some(s.value());
                     }
@@ -766,12 +1005,84 @@ fn parse_schedule_args(
                     
                     initial_delay = Some(value_str);
                 }
+                "warn_after" => {
+                    let value_str = match value {
+                        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => i.base10_digits().to_string(),
+                        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => s.value(),
+                        _ => return Err("warn_after must be int or string".to_string()),
+                    };
+
+                    if let Some(err) = validate_config_placeholder_format(&value_str, "warn_after", task_name) {
+                        return Err(err);
+                    }
+                    if let Some(err) = validate_time_suffix(&value_str, "warn_after", task_name) {
+                        return Err(err);
+                    }
+                    if let Some(err) = validate_positive_value(&value_str, "warn_after", task_name, true) {
+                        return Err(err);
+                    }
+
+                    warn_after = Some(value_str);
+                }
+                "expect_success_within" => {
+                    let value_str = match value {
+                        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => i.base10_digits().to_string(),
+                        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => s.value(),
+                        _ => return Err("expect_success_within must be int or string".to_string()),
+                    };
+
+                    if let Some(err) = validate_config_placeholder_format(&value_str, "expect_success_within", task_name) {
+                        return Err(err);
+                    }
+                    if let Some(err) = validate_time_suffix(&value_str, "expect_success_within", task_name) {
+                        return Err(err);
+                    }
+                    if let Some(err) = validate_positive_value(&value_str, "expect_success_within", task_name, true) {
+                        return Err(err);
+                    }
+
+                    expect_success_within = Some(value_str);
+                }
+                "timeout" => {
+                    let value_str = match value {
+                        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => i.base10_digits().to_string(),
+                        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => s.value(),
+                        _ => return Err("timeout must be int or string".to_string()),
+                    };
+
+                    if let Some(err) = validate_config_placeholder_format(&value_str, "timeout", task_name) {
+                        return Err(err);
+                    }
+                    if let Some(err) = validate_time_suffix(&value_str, "timeout", task_name) {
+                        return Err(err);
+                    }
+                    if let Some(err) = validate_positive_value(&value_str, "timeout", task_name, true) {
+                        return Err(err);
+                    }
+
+                    timeout = Some(value_str);
+                }
                 "enabled" => {
-                    enabled = Some(match value {
+                    let value_str = match value {
                         Expr::Lit(ExprLit { lit: Lit::Bool(b), .. }) => b.value.to_string(),
                         Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => s.value(),
                         _ => return Err("enabled must be bool or string".to_string()),
-                    });
+                    };
+
+                    if let Some(err) = validate_enabled_value(&value_str, task_name) {
+                        return Err(err);
+                    }
+
+                    enabled = Some(value_str);
+                }
+                "condition" => {
+                    if let Expr::Path(ExprPath { path, .. }) = value {
+                        condition = Some(quote::quote! { #path });
+                    } else {
+                        return Err(
+                            "condition must be a path to a function `fn(&Config) -> bool` (e.g., condition = checks::is_eu)".to_string()
+                        );
+                    }
                 }
                 "time_unit" => {
                     time_unit = Some(match value {
@@ -779,7 +1090,7 @@ fn parse_schedule_args(
                             let value_str = s.value();
                             
                             // REJECT config placeholder for time_unit
-                            if value_str.starts_with("${") {
+                            if has_placeholder(&value_str) {
                                 return Err(format!(
                                     "time_unit cannot use config placeholders like '{}'.\n\
                                      \n\
@@ -870,11 +1181,42 @@ fn parse_schedule_args(
                 }
                 "zone" => {
                     if let Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) = value {
+                        if let Some(err) = validate_placeholder_syntax(&s.value(), "zone", task_name) {
+                            return Err(err);
+                        }
                         zone = Some(s.value());
                     } else {
                         return Err("zone must be a string (e.g., 'Asia/Jakarta', 'UTC')".to_string());
                     }
                 }
+                "tags" => {
+                    tags = parse_string_array(value, "tags")?;
+                }
+                "profiles" => {
+                    profiles = parse_string_array(value, "profiles")?;
+                }
+                "name" => {
+                    match value {
+                        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) if !s.value().trim().is_empty() => {
+                            name = Some(s.value());
+                        }
+                        _ => return Err("name must be a non-empty string (e.g., name = \"billing::cleanup\")".to_string()),
+                    }
+                }
+                "description" => {
+                    if let Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) = value {
+                        description = Some(s.value());
+                    } else {
+                        return Err("description must be a string".to_string());
+                    }
+                }
+                "owner" => {
+                    if let Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) = value {
+                        owner = Some(s.value());
+                    } else {
+                        return Err("owner must be a string (e.g., 'team-billing')".to_string());
+                    }
+                }
                 _ => {}
             }
         }
@@ -886,7 +1228,7 @@ fn parse_schedule_args(
     let initial_delay_str = initial_delay.unwrap_or_else(|| "0".to_string());
     let enabled_str = enabled.unwrap_or_else(|| "true".to_string());
     let time_unit_str = time_unit.clone().unwrap_or_else(|| "milliseconds".to_string());
-    let zone_str = zone.clone().unwrap_or_else(|| "local".to_string());
+    let zone_str = zone.clone().unwrap_or_else(|| "UTC".to_string());
 
     // ========== COMPILE-TIME VALIDATIONS ==========
     
@@ -939,6 +1281,11 @@ fn parse_schedule_args(
         }
     }
 
+    // Rule 13: Cron zones must be valid; placeholders are checked at runtime
+    if schedule_type_str == "cron" && !has_placeholder(&zone_str) {
+        parse_zone(&zone_str)?;
+    }
+
     // Emit compile-time warnings for misused parameters
     if schedule_type_str == "cron" {
         // Warn if time_unit is specified for cron
@@ -956,7 +1303,8 @@ fn parse_schedule_args(
     } else {
         // Warn if zone is specified for interval tasks
         if let Some(ref z) = zone {
-            if z.to_lowercase() != "local" && !z.starts_with("${") {
+            // `local` and `UTC` are accepted silently, as the runtime does
+            if !has_placeholder(z) && !matches!(parse_zone(z), Ok(ZoneName::Local | ZoneName::Utc)) {
                 eprintln!("\nwarning[W003]: zone parameter '{}' is ignored for interval-based tasks ({})", z, schedule_type_str);
                 eprintln!("  --> task '{}'", task_name);
                 eprintln!("   |");
@@ -971,10 +1319,49 @@ fn parse_schedule_args(
         schedule_type: schedule_type_str.to_string(),
         schedule_value: schedule_value_str,
         initial_delay: initial_delay_str,
+        warn_after: warn_after.unwrap_or_default(),
+        expect_success_within: expect_success_within.unwrap_or_default(),
+        timeout: timeout.unwrap_or_default(),
         enabled: enabled_str,
+        condition,
         time_unit: time_unit_str,
         zone: zone_str,
+        name,
+        tags,
+        profiles,
+        description: description.unwrap_or_default(),
+        owner: owner.unwrap_or_default(),
         time_unit_path,
     })
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    include!("../../concerto-runtime/src/config/placeholder_syntax.rs");
+
+    #[test]
+    fn placeholder_grammar_matches_the_runtime() {
+        for (value, well_formed) in PLACEHOLDER_SYNTAX_CASES {
+            assert_eq!(scan_placeholders(value).is_ok(), *well_formed, "{}", value);
+        }
+    }
+
+    #[test]
+    fn zones_are_read_like_the_runtime() {
+        assert_eq!(parse_zone("local"), Ok(ZoneName::Local));
+        assert_eq!(parse_zone("LOCAL"), Ok(ZoneName::Local));
+        assert_eq!(parse_zone("UTC"), Ok(ZoneName::Utc));
+        assert_eq!(parse_zone("utc"), Ok(ZoneName::Utc));
+        assert_eq!(parse_zone("Asia/Jakarta"), Ok(ZoneName::Iana));
+        assert!(parse_zone("Mars/Olympus").is_err());
+        assert!(parse_zone("").is_err());
+    }
+
+    #[test]
+    fn scanned_literal_keeps_non_ascii_text() {
+        assert_eq!(scan_placeholders("é ${app.minüte:5} \\${x} ü").unwrap(), "é  ${x} ü");
+    }
+}
@@ -0,0 +1,498 @@
+use super::json;
+use crate::error::ConcertoError;
+use crate::schedule::Schedule;
+use crate::scheduler::{SchedulerEvent, SchedulerRuntime};
+use serde_json::Value;
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Largest request (head and body) the admin server reads
+const MAX_REQUEST_BYTES: u64 = 64 * 1024;
+
+/// Most header lines accepted in a request
+const MAX_HEADERS: usize = 64;
+
+/// How long a client may take to send its whole request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Comment sent on idle event streams so proxies keep them open
+const SSE_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Single-page dashboard; styles and scripts are inline, so this is the only asset
+#[cfg(feature = "dashboard")]
+const DASHBOARD: &str = include_str!("dashboard.html");
+
+/// Settings of the embedded admin HTTP server, see `SchedulerBuilder::admin_http`
+///
+/// Responses are JSON (times in RFC 3339, durations in milliseconds) unless noted.
+///
+/// | Method | Path | |
+/// |--------|------|-|
+/// | `GET`  | `/tasks` | Every task with state, schedule, next fire and last run |
+/// | `GET`  | `/tasks/{name}` | A single task |
+/// | `GET`  | `/tasks/{name}/history` | Last executions, oldest first |
+/// | `POST` | `/tasks/{name}/trigger` | Run now, in the background (`202`) |
+/// | `POST` | `/tasks/{name}/pause`, `/tasks/{name}/resume` | Pause or resume |
+/// | `PUT`  | `/tasks/{name}/schedule` | Reschedule, body `{"type": "cron", "value": "0 0 3 * * *", "zone": "UTC"}` |
+/// | `GET`  | `/health` | Health report; `503` when unhealthy |
+/// | `GET`  | `/metrics` | OpenMetrics text, with the `metrics` feature |
+/// | `GET`  | `/events` | Scheduler events as server-sent events |
+/// | `GET`  | `/` | Web dashboard, with the `dashboard` feature |
+///
+/// Task actions answer with the task as `GET /tasks/{name}` would. Errors are
+/// `{"error": "..."}` with `400`, `401`, `403`, `404` or `409`.
+///
+/// Requests must be addressed to an IP address, `localhost` or a host added with
+/// [`allow_host`](Self::allow_host), and browser requests must come from the
+/// server's own origin. Other requests get `403`. This keeps web pages open in a
+/// browser on the same machine from driving the API, by cross-site requests or
+/// DNS rebinding, even without a token.
+///
+/// # Example
+///
+/// ```rust
+/// use concerto_runtime::{AdminHttp, SchedulerBuilder};
+///
+/// let scheduler = SchedulerBuilder::new()
+///     .admin_http(AdminHttp::new("127.0.0.1:9090").bearer_token("change-me"))
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct AdminHttp {
+    bind: String,
+    token: Option<String>,
+    hosts: Vec<String>,
+}
+
+impl AdminHttp {
+    /// Listen on `bind`, e.g. `127.0.0.1:9090`; port `0` picks a free port
+    ///
+    /// The API can trigger and reschedule tasks: bind to a local or internal
+    /// address, and set a token when others can reach it.
+    pub fn new(bind: impl Into<String>) -> Self {
+        Self { bind: bind.into(), token: None, hosts: Vec::new() }
+    }
+
+    /// Require `Authorization: Bearer <token>` on every request
+    ///
+    /// The dashboard page itself is served without it; the page asks for the token.
+    pub fn bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Also accept requests addressed to `host`, a DNS name without port
+    ///
+    /// Needed when the API is reached by name, e.g. `scheduler.internal:9090`.
+    /// Can be called multiple times.
+    pub fn allow_host(mut self, host: impl Into<String>) -> Self {
+        self.hosts.push(host.into());
+        self
+    }
+}
+
+impl fmt::Debug for AdminHttp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdminHttp")
+            .field("bind", &self.bind)
+            .field("token", &self.token.as_ref().map(|_| "***"))
+            .field("hosts", &self.hosts)
+            .finish()
+    }
+}
+
+/// Bound admin server, bound before the scheduler starts so address errors abort `start`
+pub(crate) struct AdminListener {
+    listener: TcpListener,
+    access: Arc<Access>,
+}
+
+/// Who may use the API
+struct Access {
+    token: Option<String>,
+    hosts: Vec<String>,
+}
+
+impl AdminListener {
+    pub(crate) async fn bind(config: AdminHttp) -> Result<Self, ConcertoError> {
+        let listener = TcpListener::bind(&config.bind)
+            .await
+            .map_err(|source| ConcertoError::Listen { address: config.bind.clone(), source })?;
+        let access = Access { token: config.token, hosts: config.hosts };
+        Ok(Self { listener, access: Arc::new(access) })
+    }
+
+    pub(crate) fn local_addr(&self) -> Option<SocketAddr> {
+        self.listener.local_addr().ok()
+    }
+
+    /// Accept connections until the returned task is aborted
+    pub(crate) fn serve(self, runtime: Weak<SchedulerRuntime>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                let (stream, peer) = match self.listener.accept().await {
+                    Ok(connection) => connection,
+                    Err(e) => {
+                        warn!(error = %e, "Admin server failed to accept a connection");
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        continue;
+                    }
+                };
+                let runtime = runtime.clone();
+                let access = self.access.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, &runtime, &access).await {
+                        debug!(peer = %peer, error = %e, "Admin request failed");
+                    }
+                });
+            }
+        })
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    host: Option<String>,
+    origin: Option<String>,
+    authorization: Option<String>,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Response {
+    #[cfg(feature = "dashboard")]
+    fn html(page: &'static str) -> Self {
+        Self {
+            status: 200,
+            content_type: "text/html; charset=utf-8",
+            body: page.as_bytes().to_vec(),
+        }
+    }
+
+    fn json(status: u16, value: Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: value.to_string().into_bytes(),
+        }
+    }
+
+    async fn write_to<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            202 => "Accepted",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            409 => "Conflict",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        };
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            self.status,
+            reason,
+            self.content_type,
+            self.body.len()
+        );
+        if self.status == 401 {
+            head.push_str("WWW-Authenticate: Bearer\r\n");
+        }
+        head.push_str("\r\n");
+        writer.write_all(head.as_bytes()).await?;
+        writer.write_all(&self.body).await?;
+        writer.flush().await
+    }
+}
+
+/// Serve a single request; connections are closed after the response
+async fn handle_connection(
+    mut stream: TcpStream,
+    runtime: &Weak<SchedulerRuntime>,
+    access: &Access,
+) -> io::Result<()> {
+    let (reader, mut writer) = stream.split();
+    let mut reader = BufReader::new(reader.take(MAX_REQUEST_BYTES));
+    let request = match tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut reader)).await {
+        Ok(Ok(Some(request))) => request,
+        Ok(Ok(None)) => {
+            return Response::json(400, json::error("malformed request")).write_to(&mut writer).await;
+        }
+        Ok(Err(e)) => return Err(e),
+        Err(_) => return Err(io::Error::new(io::ErrorKind::TimedOut, "request not received in time")),
+    };
+
+    if let Err(message) = access.check_origin(&request) {
+        return Response::json(403, json::error(message)).write_to(&mut writer).await;
+    }
+    // The page holds no data; it asks for the token itself
+    #[cfg(feature = "dashboard")]
+    if request.method == "GET" && request.path == "/" {
+        return Response::html(DASHBOARD).write_to(&mut writer).await;
+    }
+    if let Some(token) = &access.token {
+        if !authorized(&request, token) {
+            return Response::json(401, json::error("missing or invalid bearer token"))
+                .write_to(&mut writer)
+                .await;
+        }
+    }
+    if request.method == "GET" && request.path == "/events" {
+        return stream_events(&mut writer, runtime).await;
+    }
+    respond(request, runtime).await.write_to(&mut writer).await
+}
+
+impl Access {
+    /// Reject requests for other host names (DNS rebinding) and browser requests
+    /// from other origins (cross-site requests)
+    fn check_origin(&self, request: &Request) -> Result<(), &'static str> {
+        let host = request.host.as_deref().ok_or("missing Host header")?;
+        if !self.host_allowed(host) {
+            return Err("host not allowed");
+        }
+        if let Some(origin) = &request.origin {
+            let same_origin = origin
+                .strip_prefix("http://")
+                .or_else(|| origin.strip_prefix("https://"))
+                .is_some_and(|authority| authority.eq_ignore_ascii_case(host));
+            if !same_origin {
+                return Err("cross-origin requests are not allowed");
+            }
+        }
+        Ok(())
+    }
+
+    /// IP addresses, `localhost` and configured names; `host` may carry a port
+    fn host_allowed(&self, host: &str) -> bool {
+        let name = match host.strip_prefix('[') {
+            Some(bracketed) => bracketed.split(']').next().unwrap_or_default(),
+            None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+        };
+        name.parse::<std::net::IpAddr>().is_ok()
+            || name.eq_ignore_ascii_case("localhost")
+            || self.hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(name))
+    }
+}
+
+/// `Authorization: Bearer <token>`; tokens in the URL are not accepted, as URLs end up in logs
+fn authorized(request: &Request, token: &str) -> bool {
+    let given = request.authorization.as_deref().and_then(|value| value.strip_prefix("Bearer "));
+    given.is_some_and(|given| tokens_match(given.as_bytes(), token.as_bytes()))
+}
+
+/// Send every scheduler event as a server-sent event until the scheduler
+/// stops or the client goes away
+async fn stream_events<W: AsyncWrite + Unpin>(writer: &mut W, runtime: &Weak<SchedulerRuntime>) -> io::Result<()> {
+    let Some(mut events) = runtime.upgrade().map(|runtime| runtime.subscribe()) else {
+        return Response::json(503, json::error("scheduler stopped")).write_to(writer).await;
+    };
+    writer
+        .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n")
+        .await?;
+    writer.flush().await?;
+
+    let mut keep_alive = tokio::time::interval(SSE_KEEP_ALIVE);
+    keep_alive.tick().await;
+    loop {
+        let (frame, last) = tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) => {
+                    let data = json::event(&event);
+                    let last = matches!(event, SchedulerEvent::SchedulerStopped { .. });
+                    (format!("event: {}\ndata: {}\n\n", data["type"].as_str().unwrap_or_default(), data), last)
+                }
+                Err(RecvError::Lagged(missed)) => (format!("event: lagged\ndata: {}\n\n", missed), false),
+                Err(RecvError::Closed) => break,
+            },
+            // Also notices clients that went away while nothing happens
+            _ = keep_alive.tick() => (": keep-alive\n\n".to_string(), false),
+        };
+        writer.write_all(frame.as_bytes()).await?;
+        writer.flush().await?;
+        if last {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Read the request line, the headers we use and the body
+///
+/// `None` if malformed or with more than `MAX_HEADERS` headers. The caller bounds
+/// the bytes read to `MAX_REQUEST_BYTES`.
+async fn read_request<R: AsyncBufRead + Unpin>(reader: &mut R) -> io::Result<Option<Request>> {
+    let mut line = String::new();
+    reader.read_line(&mut line).await?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Ok(None);
+    };
+    let path = path.split('?').next().unwrap_or_default();
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    let mut authorization = None;
+    let (mut host, mut origin) = (None, None);
+    for headers in 0.. {
+        if headers > MAX_HEADERS {
+            return Ok(None);
+        }
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(None);
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("authorization") {
+                authorization = Some(value.trim().to_string());
+            } else if name.eq_ignore_ascii_case("host") {
+                host = Some(value.trim().to_string());
+            } else if name.eq_ignore_ascii_case("origin") {
+                origin = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length.min(MAX_REQUEST_BYTES as usize)];
+    reader.read_exact(&mut body).await?;
+    Ok(Some(Request { method, path, host, origin, authorization, body }))
+}
+
+async fn respond(request: Request, runtime: &Weak<SchedulerRuntime>) -> Response {
+    let Some(runtime) = runtime.upgrade() else {
+        return Response::json(503, json::error("scheduler stopped"));
+    };
+
+    let path = request.path.as_str();
+    let segments: Vec<String> = path.trim_matches('/').split('/').map(percent_decode).collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["tasks"]) => {
+            let tasks = runtime.task_infos().await.iter().map(json::task_info).collect();
+            Response::json(200, Value::Array(tasks))
+        }
+        ("GET", ["tasks", name]) => task_response(&runtime, name, 200).await,
+        ("GET", ["tasks", name, "history"]) => match runtime.history(name).await {
+            Some(history) => Response::json(200, Value::Array(history.iter().map(json::execution).collect())),
+            None => error_response(ConcertoError::UnknownTask(name.to_string())),
+        },
+        ("POST", ["tasks", name, "trigger"]) => match runtime.trigger(name).await {
+            Ok(()) => task_response(&runtime, name, 202).await,
+            Err(e) => error_response(e),
+        },
+        ("POST", ["tasks", name, action @ ("pause" | "resume")]) => {
+            match runtime.set_paused(name, *action == "pause").await {
+                Ok(()) => task_response(&runtime, name, 200).await,
+                Err(e) => error_response(e),
+            }
+        }
+        ("PUT", ["tasks", name, "schedule"]) => {
+            let schedule = match parse_schedule(&request.body) {
+                Ok(schedule) => schedule,
+                Err(response) => return response,
+            };
+            match runtime.reschedule(name, &schedule).await {
+                Ok(()) => task_response(&runtime, name, 200).await,
+                Err(e) => error_response(e),
+            }
+        }
+        ("GET", ["health"]) => {
+            let report = runtime.health().await;
+            Response::json(if report.is_alive() { 200 } else { 503 }, json::health(&report))
+        }
+        #[cfg(feature = "metrics")]
+        ("GET", ["metrics"]) => Response {
+            status: 200,
+            content_type: crate::OPENMETRICS_CONTENT_TYPE,
+            body: runtime.render_metrics().await.into_bytes(),
+        },
+        (method, _) => Response::json(404, json::error(format!("no route for {} {}", method, path))),
+    }
+}
+
+async fn task_response(runtime: &SchedulerRuntime, name: &str, status: u16) -> Response {
+    match runtime.task_info(name).await {
+        Some(info) => Response::json(status, json::task_info(&info)),
+        None => error_response(ConcertoError::UnknownTask(name.to_string())),
+    }
+}
+
+fn error_response(error: ConcertoError) -> Response {
+    let status = match error {
+        ConcertoError::UnknownTask(_) => 404,
+        ConcertoError::TaskNotScheduled(_) => 409,
+        ConcertoError::InvalidCron { .. }
+        | ConcertoError::InvalidInterval { .. }
+        | ConcertoError::InvalidTimestamp { .. }
+        | ConcertoError::InvalidZone(_)
+        | ConcertoError::UnknownScheduleType(_)
+        | ConcertoError::UnsupportedSchedule(_) => 400,
+        _ => 500,
+    };
+    Response::json(status, json::error(error))
+}
+
+/// Parse `{"type": "...", "value": "...", "zone": "..."}`; `zone` only applies to cron
+fn parse_schedule(body: &[u8]) -> Result<Schedule, Response> {
+    let bad_request = |message: &str| Response::json(400, json::error(message));
+    let body: Value = serde_json::from_slice(body).map_err(|e| bad_request(&format!("invalid JSON body: {}", e)))?;
+    let (Some(schedule_type), Some(value)) = (body["type"].as_str(), body["value"].as_str()) else {
+        return Err(bad_request("expected {\"type\": \"cron|fixed_rate|fixed_delay\", \"value\": \"...\"}"));
+    };
+    let schedule = match schedule_type {
+        "cron" => Schedule::cron(value, body["zone"].as_str().unwrap_or("UTC")),
+        _ => Schedule::parse(schedule_type, value),
+    };
+    schedule.map_err(error_response)
+}
+
+/// Compare without stopping at the first difference, so timing does not reveal the token
+fn tokens_match(given: &[u8], expected: &[u8]) -> bool {
+    given.len() == expected.len() && given.iter().zip(expected).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// Decode `%XX` escapes of a path segment
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
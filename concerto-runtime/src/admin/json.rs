@@ -0,0 +1,101 @@
+#[cfg(feature = "admin-http")]
+use crate::scheduler::SchedulerEvent;
+use crate::scheduler::{HealthReport, TaskExecution, TaskInfo};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// JSON view of a task; times are RFC 3339, durations in milliseconds
+pub(crate) fn task_info(info: &TaskInfo) -> Value {
+    json!({
+        "name": info.name,
+        "kind": info.kind.to_string(),
+        "schedule": info.schedule,
+        "zone": info.zone,
+        "enabled": info.enabled,
+        "state": info.state.to_string(),
+        "next_fire": info.next_fire.map(time),
+        "last_started": info.last_started.map(time),
+        "last_finished": info.last_finished.map(time),
+        "last_success": info.last_success.map(time),
+        "last_outcome": info.last_outcome.as_ref().map(|outcome| outcome.label()),
+        "last_lateness_ms": info.last_lateness.map(millis),
+        "warn_after_ms": info.warn_after.map(millis),
+        "timeout_ms": info.timeout.map(millis),
+        "expect_success_within_ms": info.expect_success_within.map(millis),
+        "overdue": info.overdue,
+        "success_count": info.success_count,
+        "failure_count": info.failure_count,
+    })
+}
+
+pub(crate) fn execution(execution: &TaskExecution) -> Value {
+    json!({
+        "scheduled_at": time(execution.scheduled_at),
+        "started_at": time(execution.started_at),
+        "duration_ms": millis(execution.duration),
+        "lateness_ms": millis(execution.lateness()),
+        "outcome": execution.outcome.label(),
+        "error": execution.error(),
+    })
+}
+
+pub(crate) fn health(report: &HealthReport) -> Value {
+    json!({
+        "status": report.status.to_string(),
+        "cron_engine_alive": report.cron_engine_alive,
+        "missed_fires": report.missed_fires,
+        "dead_loops": report.dead_loops,
+        "failing": report.failing,
+        "stuck": report.stuck,
+        "overdue": report.overdue,
+        "checked_at": time(report.checked_at),
+    })
+}
+
+#[cfg(feature = "admin-http")]
+/// JSON view of an event: `type`, `task` (null for scheduler events), `at` and event fields
+pub(crate) fn event(event: &SchedulerEvent) -> Value {
+    let (kind, details) = match event {
+        SchedulerEvent::SchedulerStarted { .. } => ("scheduler_started", json!({})),
+        SchedulerEvent::SchedulerStopped { .. } => ("scheduler_stopped", json!({})),
+        SchedulerEvent::TaskRegistered { schedule, .. } => ("task_registered", json!({ "schedule": schedule })),
+        SchedulerEvent::TaskDisabled { reason, .. } => ("task_disabled", json!({ "reason": reason })),
+        SchedulerEvent::TaskRescheduled { old_schedule, new_schedule, .. } => (
+            "task_rescheduled",
+            json!({ "old_schedule": old_schedule, "new_schedule": new_schedule }),
+        ),
+        SchedulerEvent::TaskFired { scheduled_at, .. } => ("task_fired", json!({ "scheduled_at": time(*scheduled_at) })),
+        SchedulerEvent::TaskStarted { scheduled_at, .. } => ("task_started", json!({ "scheduled_at": time(*scheduled_at) })),
+        SchedulerEvent::TaskCompleted { execution: run, .. } => ("task_completed", json!({ "execution": execution(run) })),
+        SchedulerEvent::TaskFailed { execution: run, .. } => ("task_failed", json!({ "execution": execution(run) })),
+        SchedulerEvent::TaskStuck { started_at, elapsed, .. } => (
+            "task_stuck",
+            json!({ "started_at": time(*started_at), "elapsed_ms": millis(*elapsed) }),
+        ),
+        SchedulerEvent::Overdue { last_success, expected_within, .. } => (
+            "overdue",
+            json!({ "last_success": last_success.map(time), "expected_within_ms": millis(*expected_within) }),
+        ),
+        SchedulerEvent::TaskPaused { .. } => ("task_paused", json!({})),
+        SchedulerEvent::TaskResumed { .. } => ("task_resumed", json!({})),
+    };
+    let mut value = json!({ "type": kind, "task": event.task(), "at": time(event.at()) });
+    if let (Value::Object(value), Value::Object(details)) = (&mut value, details) {
+        value.extend(details);
+    }
+    value
+}
+
+#[cfg(feature = "admin-http")]
+pub(crate) fn error(message: impl std::fmt::Display) -> Value {
+    json!({ "error": message.to_string() })
+}
+
+fn time(at: DateTime<Utc>) -> String {
+    at.to_rfc3339()
+}
+
+fn millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
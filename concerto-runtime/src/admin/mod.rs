@@ -0,0 +1,14 @@
+//! Local endpoints to inspect and control a running scheduler
+
+#[cfg(feature = "admin-http")]
+mod http;
+mod json;
+#[cfg(all(feature = "control-socket", unix))]
+mod socket;
+
+#[cfg(feature = "admin-http")]
+pub use http::AdminHttp;
+#[cfg(feature = "admin-http")]
+pub(crate) use http::AdminListener;
+#[cfg(all(feature = "control-socket", unix))]
+pub(crate) use socket::ControlListener;
@@ -0,0 +1,171 @@
+use super::json;
+use crate::error::ConcertoError;
+use crate::scheduler::SchedulerRuntime;
+use serde_json::{json, Value};
+use std::io;
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::Weak;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// How long `drain` waits for running tasks unless the request sets `timeout_ms`
+const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Bound control socket; the socket file is removed when the listener is dropped
+pub(crate) struct ControlListener {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+impl ControlListener {
+    /// Bind `path`, readable and writable by the owner only
+    ///
+    /// A socket left behind by a process that is gone is replaced. A socket
+    /// another process still listens on, or any other kind of file, is an error.
+    pub(crate) async fn bind(path: PathBuf) -> Result<Self, ConcertoError> {
+        let listen_error = |source| ConcertoError::Listen { address: path.display().to_string(), source };
+        match std::fs::symlink_metadata(&path) {
+            Ok(metadata) if !metadata.file_type().is_socket() => {
+                return Err(listen_error(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    "a file that is not a socket exists at this path",
+                )));
+            }
+            Ok(_) => {
+                if UnixStream::connect(&path).await.is_ok() {
+                    return Err(listen_error(io::Error::new(
+                        io::ErrorKind::AddrInUse,
+                        "another process is listening on this socket",
+                    )));
+                }
+                std::fs::remove_file(&path).map_err(listen_error)?;
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(listen_error(e)),
+        }
+        let listener = bind_private(&path).map_err(listen_error)?;
+        Ok(Self { listener, path })
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Accept connections until the returned task is aborted
+    pub(crate) fn serve(self, runtime: Weak<SchedulerRuntime>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                let stream = match self.listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        warn!(error = %e, "Control socket failed to accept a connection");
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        continue;
+                    }
+                };
+                let runtime = runtime.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, &runtime).await {
+                        debug!(error = %e, "Control connection failed");
+                    }
+                });
+            }
+        })
+    }
+}
+
+/// Bind inside a fresh owner-only directory next to `path`, restrict the socket,
+/// then move it into place, so it is never reachable with looser permissions
+fn bind_private(path: &Path) -> io::Result<UnixListener> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let private_dir = parent.join(format!(".concerto-{}-{}", std::process::id(), uuid::Uuid::new_v4().simple()));
+    std::fs::DirBuilder::new().mode(0o700).create(&private_dir)?;
+
+    let staged = private_dir.join("control.sock");
+    let result = UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_file(&staged);
+    let _ = std::fs::remove_dir(&private_dir);
+    result
+}
+
+impl Drop for ControlListener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Answer each request line with one response line until the client hangs up
+async fn handle_connection(stream: UnixStream, runtime: &Weak<SchedulerRuntime>) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match runtime.upgrade() {
+            Some(runtime) => execute(&line, &runtime).await,
+            None => Err("scheduler stopped".to_string()),
+        };
+        let response = match response {
+            Ok(result) => json!({ "ok": true, "result": result }),
+            Err(error) => json!({ "ok": false, "error": error }),
+        };
+        writer.write_all(format!("{}\n", response).as_bytes()).await?;
+    }
+    Ok(())
+}
+
+/// Run one request, e.g. `{"command": "pause", "task": "cleanup"}`
+async fn execute(line: &str, runtime: &SchedulerRuntime) -> Result<Value, String> {
+    let request: Value = serde_json::from_str(line).map_err(|e| format!("invalid request: {}", e))?;
+    let command = request["command"].as_str().ok_or("missing \"command\"")?;
+    let task = request["task"].as_str();
+
+    match (command, task) {
+        ("list", _) => Ok(Value::Array(runtime.task_infos().await.iter().map(json::task_info).collect())),
+        ("status", None) => Ok(json::health(&runtime.health().await)),
+        ("status", Some(name)) => {
+            let mut status = task_info(runtime, name).await?;
+            let history = runtime.history(name).await.unwrap_or_default();
+            status["history"] = history.iter().map(json::execution).collect();
+            Ok(status)
+        }
+        ("trigger", Some(name)) => {
+            runtime.trigger(name).await.map_err(|e| e.to_string())?;
+            task_info(runtime, name).await
+        }
+        ("pause" | "resume", Some(name)) => {
+            runtime.set_paused(name, command == "pause").await.map_err(|e| e.to_string())?;
+            task_info(runtime, name).await
+        }
+        ("reload-config", _) => {
+            runtime.reload().await.map_err(|e| e.to_string())?;
+            Ok(Value::Array(runtime.task_infos().await.iter().map(json::task_info).collect()))
+        }
+        ("drain", _) => {
+            let timeout = request["timeout_ms"].as_u64().map(Duration::from_millis).unwrap_or(DEFAULT_DRAIN_TIMEOUT);
+            let running = runtime.drain(timeout).await;
+            Ok(json!({ "drained": running.is_empty(), "running": running }))
+        }
+        ("trigger" | "pause" | "resume", None) => Err(format!("'{}' needs a \"task\"", command)),
+        _ => Err(format!("unknown command '{}'", command)),
+    }
+}
+
+async fn task_info(runtime: &SchedulerRuntime, name: &str) -> Result<Value, String> {
+    match runtime.task_info(name).await {
+        Some(info) => Ok(json::task_info(&info)),
+        None => Err(ConcertoError::UnknownTask(name.to_string()).to_string()),
+    }
+}
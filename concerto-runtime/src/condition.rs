@@ -0,0 +1,426 @@
+use crate::config::resolve_config_value;
+use crate::error::ConcertoError;
+use config::Config;
+
+/// Predicate deciding at start time whether a task is registered
+///
+/// Used with `#[scheduled(..., condition = path::to::fn)]`.
+pub type TaskCondition = fn(&Config) -> bool;
+
+/// Evaluate an `enabled` value into a boolean
+///
+/// Accepts plain booleans, config placeholders and simple boolean expressions:
+///
+/// ```text
+/// true
+/// ${feature.sync}
+/// ${feature.sync:false}
+/// !${maintenance.active}
+/// ${feature.sync} && ${region} == 'eu'
+/// (${region} == 'eu' || ${region} == "us") && !${dry_run:false}
+/// ${mode} != \${literal}
+/// ```
+///
+/// Operands that are used as booleans must be `true` or `false` (case-insensitive).
+/// Any other value is reported as an error instead of silently enabling the task.
+/// `&&` and `||` short-circuit: placeholders in an operand that cannot change the
+/// result are not resolved, so they may be missing from the config.
+pub fn evaluate_enabled(expression: &str, config: &Config) -> Result<bool, ConcertoError> {
+    let tokens = tokenize(expression)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        position: 0,
+        expression,
+        config,
+        skipping: false,
+    };
+
+    let value = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        return Err(ConcertoError::InvalidEnabled(format!(
+            "Unexpected '{}' in enabled expression '{}'",
+            token, expression
+        )));
+    }
+
+    value.into_bool(expression)
+}
+
+/// Parse a string as a strict boolean
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    And,
+    Or,
+    Not,
+    Eq,
+    NotEq,
+    OpenParen,
+    CloseParen,
+    Placeholder(String),
+    Quoted(String),
+    Word(String),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::And => write!(f, "&&"),
+            Token::Or => write!(f, "||"),
+            Token::Not => write!(f, "!"),
+            Token::Eq => write!(f, "=="),
+            Token::NotEq => write!(f, "!="),
+            Token::OpenParen => write!(f, "("),
+            Token::CloseParen => write!(f, ")"),
+            Token::Placeholder(p) => write!(f, "{}", p),
+            Token::Quoted(q) => write!(f, "'{}'", q),
+            Token::Word(w) => write!(f, "{}", w),
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, ConcertoError> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '&' if next == Some('&') => {
+                tokens.push(Token::And);
+                i += 2;
+            }
+            '|' if next == Some('|') => {
+                tokens.push(Token::Or);
+                i += 2;
+            }
+            '=' if next == Some('=') => {
+                tokens.push(Token::Eq);
+                i += 2;
+            }
+            '!' if next == Some('=') => {
+                tokens.push(Token::NotEq);
+                i += 2;
+            }
+            '!' => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::OpenParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::CloseParen);
+                i += 1;
+            }
+            '$' if next == Some('{') => {
+                let end = closing_brace(&chars, i, expression)?;
+                tokens.push(Token::Placeholder(chars[i..=end].iter().collect()));
+                i = end + 1;
+            }
+            '\\' if next == Some('$') && chars.get(i + 2) == Some(&'{') => {
+                // Escaped placeholder, kept as literal text without the backslash
+                let end = closing_brace(&chars, i + 1, expression)?;
+                tokens.push(Token::Quoted(chars[i + 1..=end].iter().collect()));
+                i = end + 1;
+            }
+            '\'' | '"' => {
+                let quote = c;
+                let start = i + 1;
+                let end = chars[start..]
+                    .iter()
+                    .position(|&ch| ch == quote)
+                    .map(|offset| start + offset)
+                    .ok_or_else(|| {
+                        ConcertoError::InvalidEnabled(format!(
+                            "Unclosed quote in enabled expression '{}'",
+                            expression
+                        ))
+                    })?;
+                tokens.push(Token::Quoted(chars[start..end].iter().collect()));
+                i = end + 1;
+            }
+            _ if c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '/') => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '-' | '.' | ':' | '/'))
+                {
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            }
+            _ => {
+                return Err(ConcertoError::InvalidEnabled(format!(
+                    "Unexpected character '{}' in enabled expression '{}'",
+                    c, expression
+                )));
+            }
+        }
+    }
+
+    if tokens.is_empty() {
+        return Err(ConcertoError::InvalidEnabled("enabled expression is empty".to_string()));
+    }
+
+    Ok(tokens)
+}
+
+/// Index of the brace closing the placeholder starting at `start` (placeholders may nest in defaults)
+fn closing_brace(chars: &[char], start: usize, expression: &str) -> Result<usize, ConcertoError> {
+    let mut depth = 0;
+    for (i, &c) in chars.iter().enumerate().skip(start) {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(ConcertoError::InvalidEnabled(format!(
+        "Unclosed placeholder in enabled expression '{}'",
+        expression
+    )))
+}
+
+/// Intermediate value of an expression: either a raw string or a computed boolean
+enum Value {
+    Text(String),
+    Bool(bool),
+}
+
+impl Value {
+    fn into_bool(self, expression: &str) -> Result<bool, ConcertoError> {
+        match self {
+            Value::Bool(b) => Ok(b),
+            Value::Text(text) => parse_bool(&text).ok_or_else(|| {
+                ConcertoError::InvalidEnabled(format!(
+                    "Invalid boolean value '{}' in enabled expression '{}' (expected 'true' or 'false')",
+                    text, expression
+                ))
+            }),
+        }
+    }
+
+    fn into_text(self) -> String {
+        match self {
+            Value::Text(text) => text.trim().to_string(),
+            Value::Bool(b) => b.to_string(),
+        }
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    expression: &'a str,
+    config: &'a Config,
+    /// Parsing an operand that cannot change the result; placeholders are not resolved
+    skipping: bool,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Value, ConcertoError> {
+        let mut value = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            let left = value.into_bool(self.expression)?;
+            let right = self.parse_operand(left, Self::parse_and)?.into_bool(self.expression)?;
+            value = Value::Bool(left || right);
+        }
+        Ok(value)
+    }
+
+    fn parse_and(&mut self) -> Result<Value, ConcertoError> {
+        let mut value = self.parse_unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            let left = value.into_bool(self.expression)?;
+            let right = self.parse_operand(!left, Self::parse_unary)?.into_bool(self.expression)?;
+            value = Value::Bool(left && right);
+        }
+        Ok(value)
+    }
+
+    /// Parse the right operand of `&&`/`||`, without resolving placeholders when `skip` is set
+    fn parse_operand(
+        &mut self,
+        skip: bool,
+        parse: fn(&mut Self) -> Result<Value, ConcertoError>,
+    ) -> Result<Value, ConcertoError> {
+        let skipping = self.skipping;
+        self.skipping |= skip;
+        let value = parse(self);
+        self.skipping = skipping;
+        value
+    }
+
+    fn parse_unary(&mut self) -> Result<Value, ConcertoError> {
+        if self.peek() == Some(&Token::Not) {
+            self.next();
+            let value = self.parse_unary()?.into_bool(self.expression)?;
+            return Ok(Value::Bool(!value));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Value, ConcertoError> {
+        let left = self.parse_primary()?;
+        match self.peek() {
+            Some(Token::Eq) => {
+                self.next();
+                let right = self.parse_primary()?;
+                Ok(Value::Bool(left.into_text() == right.into_text()))
+            }
+            Some(Token::NotEq) => {
+                self.next();
+                let right = self.parse_primary()?;
+                Ok(Value::Bool(left.into_text() != right.into_text()))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Value, ConcertoError> {
+        match self.next() {
+            Some(Token::OpenParen) => {
+                let value = self.parse_or()?;
+                match self.next() {
+                    Some(Token::CloseParen) => Ok(value),
+                    _ => Err(ConcertoError::InvalidEnabled(format!(
+                        "Missing closing ')' in enabled expression '{}'",
+                        self.expression
+                    ))),
+                }
+            }
+            Some(Token::Placeholder(_)) if self.skipping => Ok(Value::Bool(false)),
+            Some(Token::Placeholder(placeholder)) => {
+                Ok(Value::Text(resolve_config_value(&placeholder, self.config)?))
+            }
+            Some(Token::Quoted(text)) | Some(Token::Word(text)) => Ok(Value::Text(text)),
+            Some(token) => Err(ConcertoError::InvalidEnabled(format!(
+                "Unexpected '{}' in enabled expression '{}'",
+                token, self.expression
+            ))),
+            None => Err(ConcertoError::InvalidEnabled(format!(
+                "Unexpected end of enabled expression '{}'",
+                self.expression
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config::builder()
+            .set_override("feature.sync", true)
+            .unwrap()
+            .set_override("feature.off", false)
+            .unwrap()
+            .set_override("region", "eu")
+            .unwrap()
+            .set_override("mode", "${literal}")
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    fn eval(expression: &str) -> Result<bool, ConcertoError> {
+        evaluate_enabled(expression, &config())
+    }
+
+    #[test]
+    fn plain_values_and_placeholders() {
+        assert!(eval("true").unwrap());
+        assert!(!eval("FALSE").unwrap());
+        assert!(eval("${feature.sync}").unwrap());
+        assert!(!eval("${feature.missing:false}").unwrap());
+        assert!(eval("!${feature.off}").unwrap());
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert!(eval("true || false && false").unwrap());
+        assert!(!eval("(true || false) && false").unwrap());
+        assert!(eval("!false && true").unwrap());
+        assert!(!eval("!(true && true)").unwrap());
+    }
+
+    #[test]
+    fn comparisons_with_quotes() {
+        assert!(eval("${region} == 'eu'").unwrap());
+        assert!(eval("${region} == \"eu\"").unwrap());
+        assert!(eval("${region} != 'us'").unwrap());
+        assert!(eval("(${region} == 'us' || ${region} == 'eu') && !${feature.off}").unwrap());
+        assert!(eval("'a b' == 'a b'").unwrap());
+    }
+
+    #[test]
+    fn short_circuit_skips_unresolved_operands() {
+        assert!(!eval("${feature.off} && ${missing.key} == 'eu'").unwrap());
+        assert!(eval("${feature.sync} || ${missing.key}").unwrap());
+        assert!(!eval("false && (${missing.a} || ${missing.b})").unwrap());
+        assert!(eval("${feature.missing:false} && ${region} == 'eu' || true").unwrap());
+        assert!(eval("${feature.sync} && ${missing.key}").is_err());
+        assert!(eval("${feature.off} || ${missing.key}").is_err());
+    }
+
+    #[test]
+    fn escaped_placeholder_is_literal() {
+        assert!(eval("${mode} == \\${literal}").unwrap());
+        assert!(eval("${region} != \\${region}").unwrap());
+    }
+
+    #[test]
+    fn malformed_expressions_are_errors() {
+        for expression in [
+            "",
+            "   ",
+            "${feature.sync",
+            "'eu",
+            "(true",
+            "true)",
+            "true &&",
+            "&& true",
+            "true & false",
+            "${region}",
+            "maybe",
+            "true false",
+            "\\${unclosed",
+        ] {
+            assert!(
+                matches!(eval(expression), Err(ConcertoError::InvalidEnabled(_))),
+                "'{}' should be rejected",
+                expression
+            );
+        }
+    }
+}
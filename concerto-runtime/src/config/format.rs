@@ -0,0 +1,294 @@
+use config::{FileFormat, FileStoredFormat, Format, Map, Value, ValueKind};
+use std::error::Error;
+use std::path::Path;
+
+/// Format of a config file
+///
+/// Wraps the formats supported by the `config` crate and adds Java-style
+/// `.properties` files for applications migrating from Spring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+    Json5,
+    Ini,
+    Ron,
+    Properties,
+}
+
+impl ConfigFormat {
+    const ALL: [ConfigFormat; 7] = [
+        ConfigFormat::Toml,
+        ConfigFormat::Yaml,
+        ConfigFormat::Json,
+        ConfigFormat::Json5,
+        ConfigFormat::Ini,
+        ConfigFormat::Ron,
+        ConfigFormat::Properties,
+    ];
+
+    /// Detect the format from a file extension (`application.yml` -> `Yaml`)
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|format| format.file_extensions().contains(&extension.as_str()))
+    }
+
+    /// Format handled by the `config` crate, `None` for formats parsed here
+    fn native(self) -> Option<FileFormat> {
+        match self {
+            ConfigFormat::Toml => Some(FileFormat::Toml),
+            ConfigFormat::Yaml => Some(FileFormat::Yaml),
+            ConfigFormat::Json => Some(FileFormat::Json),
+            ConfigFormat::Json5 => Some(FileFormat::Json5),
+            ConfigFormat::Ini => Some(FileFormat::Ini),
+            ConfigFormat::Ron => Some(FileFormat::Ron),
+            ConfigFormat::Properties => None,
+        }
+    }
+}
+
+impl Format for ConfigFormat {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
+        match self.native() {
+            Some(format) => format.parse(uri, text),
+            None => Ok(parse_properties(uri, text)),
+        }
+    }
+}
+
+impl FileStoredFormat for ConfigFormat {
+    fn file_extensions(&self) -> &'static [&'static str] {
+        match self {
+            ConfigFormat::Properties => &["properties"],
+            _ => self.native().map(|format| format.file_extensions()).unwrap_or(&[]),
+        }
+    }
+}
+
+/// Parse a Java-style `.properties` file into a nested table
+///
+/// Supports `key=value`, `key: value` and `key value` lines, `#`/`!` comments,
+/// trailing-backslash line continuations and the usual escapes (`\n`, `\t`, `\uXXXX`).
+/// Dotted keys (`app.interval`) become nested tables so they can be read with
+/// the same paths as in YAML or TOML.
+fn parse_properties(uri: Option<&String>, text: &str) -> Map<String, Value> {
+    let mut root = Map::new();
+
+    for line in logical_lines(text) {
+        let (key, value) = split_property(&line);
+        if key.is_empty() {
+            continue;
+        }
+        let path: Vec<&str> = key.split('.').collect();
+        insert_property(&mut root, &path, Value::new(uri, value), uri);
+    }
+
+    root
+}
+
+/// Join continuation lines and drop blank lines and comments
+fn logical_lines(text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for raw in text.lines() {
+        let line = raw.trim_start();
+        if current.is_empty() && (line.is_empty() || line.starts_with('#') || line.starts_with('!')) {
+            continue;
+        }
+
+        // An odd number of trailing backslashes continues the line
+        let trailing = line.chars().rev().take_while(|&c| c == '\\').count();
+        if trailing % 2 == 1 {
+            current.push_str(&line[..line.len() - 1]);
+        } else {
+            current.push_str(line);
+            lines.push(std::mem::take(&mut current));
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Split a logical line into an unescaped key and value
+fn split_property(line: &str) -> (String, String) {
+    let chars: Vec<char> = line.chars().collect();
+    let mut key_end = chars.len();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '=' | ':' => {
+                key_end = i;
+                break;
+            }
+            c if c.is_whitespace() => {
+                key_end = i;
+                break;
+            }
+            _ => i += 1,
+        }
+    }
+    let key_end = key_end.min(chars.len());
+
+    // Skip whitespace, then at most one separator, then whitespace again
+    let mut value_start = key_end;
+    while value_start < chars.len() && chars[value_start].is_whitespace() {
+        value_start += 1;
+    }
+    if value_start < chars.len() && matches!(chars[value_start], '=' | ':') {
+        value_start += 1;
+    }
+    while value_start < chars.len() && chars[value_start].is_whitespace() {
+        value_start += 1;
+    }
+
+    let key: String = chars[..key_end].iter().collect();
+    let value: String = chars[value_start..].iter().collect();
+    (unescape(&key), unescape(&value))
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('f') => result.push('\u{000C}'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => result.push(decoded),
+                    None => {
+                        result.push_str("\\u");
+                        result.push_str(&hex);
+                    }
+                }
+            }
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+
+    result
+}
+
+/// Insert a value at a dotted path, creating intermediate tables
+///
+/// When a key is both a value and a parent (`a=1` and `a.b=2`), the table wins.
+fn insert_property(table: &mut Map<String, Value>, path: &[&str], value: Value, uri: Option<&String>) {
+    let (first, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+
+    if rest.is_empty() {
+        let is_table = matches!(table.get(*first), Some(existing) if matches!(existing.kind, ValueKind::Table(_)));
+        if !is_table {
+            table.insert(first.to_string(), value);
+        }
+        return;
+    }
+
+    let entry = table
+        .entry(first.to_string())
+        .or_insert_with(|| Value::new(uri, ValueKind::Table(Map::new())));
+    if !matches!(entry.kind, ValueKind::Table(_)) {
+        *entry = Value::new(uri, ValueKind::Table(Map::new()));
+    }
+    if let ValueKind::Table(child) = &mut entry.kind {
+        insert_property(child, rest, value, uri);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, Source};
+
+    fn load(text: &str) -> Config {
+        Config::builder()
+            .add_source(File::from_str(text, ConfigFormat::Properties))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn separators_and_whitespace() {
+        let config = load("a=1\nb: 2\nc 3\n  d  =  4  \ne\t:\t5\nurl=http://host:80/x=y\nempty\nblank=\n");
+        for (key, expected) in [("a", "1"), ("b", "2"), ("c", "3"), ("d", "4  "), ("e", "5")] {
+            assert_eq!(config.get_string(key).unwrap(), expected, "key {}", key);
+        }
+        assert_eq!(config.get_string("url").unwrap(), "http://host:80/x=y");
+        assert_eq!(config.get_string("empty").unwrap(), "");
+        assert_eq!(config.get_string("blank").unwrap(), "");
+    }
+
+    #[test]
+    fn comments_and_blank_lines() {
+        let config = load("# comment\n! also a comment\n\n   \nkey=value # not a comment\n");
+        assert_eq!(config.get_string("key").unwrap(), "value # not a comment");
+        assert_eq!(config.collect().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn continuation_lines() {
+        let config = load("list=a,\\\n    b,\\\n    c\nnext=1\nhash=x\\\n# kept\n");
+        assert_eq!(config.get_string("list").unwrap(), "a,b,c");
+        assert_eq!(config.get_string("next").unwrap(), "1");
+        assert_eq!(config.get_string("hash").unwrap(), "x# kept");
+
+        // An even number of trailing backslashes is an escaped backslash
+        let config = load("path=C:\\\\\nother=2\n");
+        assert_eq!(config.get_string("path").unwrap(), "C:\\");
+        assert_eq!(config.get_string("other").unwrap(), "2");
+    }
+
+    #[test]
+    fn escapes() {
+        let config = load("text=a\\tb\\nc\nletter=\\u0041\\u00e9\nbad=\\uZZ\nkey\\=with\\:seps=1\nspaced\\ key=2\n");
+        assert_eq!(config.get_string("text").unwrap(), "a\tb\nc");
+        assert_eq!(config.get_string("letter").unwrap(), "Aé");
+        assert_eq!(config.get_string("bad").unwrap(), "\\uZZ");
+        let table = config.collect().unwrap();
+        assert_eq!(table["key=with:seps"].clone().into_string().unwrap(), "1");
+        assert_eq!(table["spaced key"].clone().into_string().unwrap(), "2");
+    }
+
+    #[test]
+    fn dotted_keys_become_tables() {
+        let config = load("app.interval=5s\napp.jobs.sync=true\napp=flat\napp.zone=UTC\n");
+        assert_eq!(config.get_string("app.interval").unwrap(), "5s");
+        assert!(config.get_bool("app.jobs.sync").unwrap());
+        assert_eq!(config.get_string("app.zone").unwrap(), "UTC");
+        // A key that is both a value and a parent keeps the table
+        assert!(config.get_table("app").is_ok());
+    }
+
+    #[test]
+    fn detects_format_from_extension() {
+        assert_eq!(ConfigFormat::from_path("application.properties"), Some(ConfigFormat::Properties));
+        assert_eq!(ConfigFormat::from_path("config/app.YML"), Some(ConfigFormat::Yaml));
+        assert_eq!(ConfigFormat::from_path("app.json5"), Some(ConfigFormat::Json5));
+        assert_eq!(ConfigFormat::from_path("app.txt"), None);
+        assert_eq!(ConfigFormat::from_path("Makefile"), None);
+    }
+}
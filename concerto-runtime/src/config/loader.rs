@@ -0,0 +1,352 @@
+use super::format::ConfigFormat;
+use crate::error::ConcertoError;
+use crate::profile::{split_profiles, ACTIVE_PROFILES_ENV, ACTIVE_PROFILES_KEY};
+use config::{Config, Environment, File, Map};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Config table holding the `.env` entries, read by `${env:VAR}` placeholders
+pub(crate) const DOTENV_KEY: &str = "concerto.dotenv";
+
+/// Builder stacking several config sources in order of precedence
+///
+/// Sources are applied from lowest to highest precedence:
+///
+/// 1. Base files, in the order they were added
+/// 2. Profile files (`application-prod.yaml`) for each active profile
+/// 3. The `.env` file
+/// 4. Environment variables with the configured prefix (`APP_` by default)
+/// 5. Command-line overrides (`--app.interval=10s`)
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use concerto_runtime::{ConfigLoader, SchedulerBuilder};
+///
+/// let loader = ConfigLoader::new()
+///     .file("config/application.yaml")
+///     .file("config/legacy.properties")
+///     .dotenv(".env")
+///     .env_prefix("MYAPP")
+///     .args(std::env::args());
+///
+/// let scheduler = SchedulerBuilder::with_loader(loader).build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfigLoader {
+    files: Vec<(PathBuf, Option<ConfigFormat>)>,
+    dotenv: Option<PathBuf>,
+    env_prefix: String,
+    env_separator: String,
+    overrides: Vec<(String, String)>,
+    profiles: Option<Vec<String>>,
+}
+
+impl Default for ConfigLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfigLoader {
+    /// Create a loader without files, reading `APP_*` environment variables
+    pub fn new() -> Self {
+        Self {
+            files: Vec::new(),
+            dotenv: None,
+            env_prefix: "APP".to_string(),
+            env_separator: "_".to_string(),
+            overrides: Vec::new(),
+            profiles: None,
+        }
+    }
+
+    /// Add a base config file, detecting the format from its extension
+    ///
+    /// Supported extensions: `toml`, `yaml`/`yml`, `json`, `json5`, `ini`, `ron`
+    /// and `properties`. Later files override earlier ones.
+    pub fn file(mut self, path: impl AsRef<Path>) -> Self {
+        self.files.push((path.as_ref().to_path_buf(), None));
+        self
+    }
+
+    /// Add a base config file with an explicit format
+    pub fn file_with_format(mut self, path: impl AsRef<Path>, format: ConfigFormat) -> Self {
+        self.files.push((path.as_ref().to_path_buf(), Some(format)));
+        self
+    }
+
+    /// Read `KEY=VALUE` pairs from a `.env` file
+    ///
+    /// The file is optional. Its entries are treated like environment variables,
+    /// so only keys with the env prefix become config values, and real
+    /// environment variables win over entries in the file. `${env:VAR}`
+    /// placeholders see every entry.
+    pub fn dotenv(mut self, path: impl AsRef<Path>) -> Self {
+        self.dotenv = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set the environment variable prefix (default `APP`)
+    ///
+    /// With prefix `MYAPP`, `MYAPP_SERVER_PORT` maps to `server.port`.
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.env_prefix = prefix.to_string();
+        self
+    }
+
+    /// Set the separator between key segments in environment variables (default `_`)
+    ///
+    /// Use `__` to keep underscores inside keys: `APP__MAX_RETRIES` maps to `max_retries`.
+    pub fn env_separator(mut self, separator: &str) -> Self {
+        self.env_separator = separator.to_string();
+        self
+    }
+
+    /// Override a single key, taking precedence over every other source
+    pub fn set_override(mut self, key: &str, value: &str) -> Self {
+        self.overrides.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Read overrides from command-line arguments
+    ///
+    /// Spring-style `--key=value` arguments become overrides; every other
+    /// argument (including the program name) is ignored.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for arg in args {
+            if let Some((key, value)) = arg.as_ref().strip_prefix("--").and_then(|a| a.split_once('=')) {
+                if !key.is_empty() {
+                    self.overrides.push((key.to_string(), value.to_string()));
+                }
+            }
+        }
+        self
+    }
+
+    /// Set the profiles whose files are layered over the base files
+    ///
+    /// Without this, profiles are read from `concerto.profiles.active` in the
+    /// overrides, then `CONCERTO_PROFILES_ACTIVE` (environment or `.env`),
+    /// then `concerto.profiles.active` in the loaded config.
+    pub fn profiles<I, S>(mut self, profiles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.profiles = Some(profiles.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Build the config from all sources
+    pub fn load(&self) -> Result<Config, ConcertoError> {
+        self.load_with_profiles().map(|(config, _)| config)
+    }
+
+    /// Build the config and return the profiles that were applied
+    pub(crate) fn load_with_profiles(&self) -> Result<(Config, Vec<String>), ConcertoError> {
+        let dotenv = self.dotenv_variables()?;
+        let mut environment = dotenv.clone();
+        environment.extend(std::env::vars());
+
+        let profiles = match &self.profiles {
+            Some(profiles) => profiles.clone(),
+            None => {
+                let config = self.build(&environment, &dotenv, &[])?;
+                self.detect_profiles(&environment, &config)
+            }
+        };
+
+        let config = self.build(&environment, &dotenv, &profiles)?;
+        Ok((config, profiles))
+    }
+
+    /// Files read by `load`: base files, profile files for the configured profiles and `.env`
+    pub(crate) fn watched_paths(&self) -> Vec<PathBuf> {
+        let profiles = self.profiles.as_deref().unwrap_or_default();
+        let mut paths: Vec<PathBuf> = self.files.iter().map(|(path, _)| path.clone()).collect();
+        for profile in profiles {
+            paths.extend(self.files.iter().map(|(path, _)| profile_config_path(path, profile)));
+        }
+        paths.extend(self.dotenv.clone());
+        paths
+    }
+
+    /// `environment` is the process environment over the `.env` entries in `dotenv`
+    fn build(
+        &self,
+        environment: &Map<String, String>,
+        dotenv: &Map<String, String>,
+        profiles: &[String],
+    ) -> Result<Config, ConcertoError> {
+        let mut builder = Config::builder();
+
+        for (path, format) in &self.files {
+            builder = builder.add_source(File::new(&path_str(path)?, file_format(path, *format)?));
+        }
+
+        for profile in profiles {
+            for (path, format) in &self.files {
+                let profile_path = profile_config_path(path, profile);
+                debug!(
+                    profile = %profile,
+                    path = %profile_path.display(),
+                    "Layering profile config file"
+                );
+                builder = builder.add_source(
+                    File::new(&path_str(&profile_path)?, file_format(path, *format)?).required(false),
+                );
+            }
+        }
+
+        builder = builder.add_source(
+            Environment::with_prefix(&self.env_prefix)
+                .separator(&self.env_separator)
+                .source(Some(environment.clone())),
+        );
+
+        if !dotenv.is_empty() {
+            builder = builder.set_override(DOTENV_KEY, dotenv.clone())?;
+        }
+
+        for (key, value) in &self.overrides {
+            builder = builder.set_override(key.as_str(), value.as_str())?;
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Entries of the `.env` file, empty without one
+    fn dotenv_variables(&self) -> Result<Map<String, String>, ConcertoError> {
+        let mut variables = Map::new();
+
+        if let Some(path) = &self.dotenv {
+            match std::fs::read_to_string(path) {
+                Ok(text) => variables.extend(parse_dotenv(&text)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    debug!(path = %path.display(), "No .env file found, skipping");
+                }
+                Err(e) => {
+                    return Err(ConcertoError::ConfigLoad(format!(
+                        "Failed to read '{}': {}",
+                        path.display(),
+                        e
+                    )));
+                }
+            }
+        }
+
+        Ok(variables)
+    }
+
+    fn detect_profiles(&self, environment: &Map<String, String>, config: &Config) -> Vec<String> {
+        if let Some((_, value)) = self.overrides.iter().rev().find(|(key, _)| key == ACTIVE_PROFILES_KEY) {
+            return split_profiles(value);
+        }
+        if let Some(value) = environment.get(ACTIVE_PROFILES_ENV) {
+            return split_profiles(value);
+        }
+        crate::profile::active_profiles_from(config)
+    }
+}
+
+/// Build the path of a profile-specific config file
+///
+/// `config/application.yaml` + `prod` -> `config/application-prod.yaml`
+fn profile_config_path(path: &Path, profile: &str) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    let file_name = match path.extension().and_then(|e| e.to_str()) {
+        Some(extension) => format!("{}-{}.{}", stem, profile, extension),
+        None => format!("{}-{}", stem, profile),
+    };
+    path.with_file_name(file_name)
+}
+
+fn path_str(path: &Path) -> Result<String, ConcertoError> {
+    path.to_str()
+        .map(str::to_string)
+        .ok_or_else(|| ConcertoError::ConfigLoad(format!("Config path '{}' is not valid UTF-8", path.display())))
+}
+
+fn file_format(path: &Path, format: Option<ConfigFormat>) -> Result<ConfigFormat, ConcertoError> {
+    format.or_else(|| ConfigFormat::from_path(path)).ok_or_else(|| {
+        ConcertoError::ConfigLoad(format!(
+            "Cannot detect the format of config file '{}'. Use a known extension \
+             (toml, yaml, yml, json, json5, ini, ron, properties) or `file_with_format`",
+            path.display()
+        ))
+    })
+}
+
+/// Parse `KEY=VALUE` lines of a `.env` file
+///
+/// Blank lines and `#` comments are skipped, an optional `export ` prefix is
+/// allowed and values may be wrapped in single or double quotes.
+fn parse_dotenv(text: &str) -> Vec<(String, String)> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = match value.chars().next() {
+                Some(quote @ ('"' | '\'')) if value.len() >= 2 && value.ends_with(quote) => {
+                    &value[1..value.len() - 1]
+                }
+                _ => value,
+            };
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn dotenv_entries() {
+        let text = "A=1\n  B = two words  \nexport C=3\nD=x=y\nE=\n";
+        assert_eq!(parse_dotenv(text), pairs(&[("A", "1"), ("B", "two words"), ("C", "3"), ("D", "x=y"), ("E", "")]));
+    }
+
+    #[test]
+    fn dotenv_quotes() {
+        let text = "A=\"quoted value\"\nB='single'\nC=\"unbalanced\nD=\"\nE=\"mixed'\nF=\"\"\n";
+        assert_eq!(
+            parse_dotenv(text),
+            pairs(&[
+                ("A", "quoted value"),
+                ("B", "single"),
+                ("C", "\"unbalanced"),
+                ("D", "\""),
+                ("E", "\"mixed'"),
+                ("F", ""),
+            ])
+        );
+    }
+
+    #[test]
+    fn dotenv_skips_comments_and_malformed_lines() {
+        let text = "# comment\n\n   # indented comment\nno_separator\nA=1 # kept\n";
+        assert_eq!(parse_dotenv(text), pairs(&[("A", "1 # kept")]));
+    }
+
+    #[test]
+    fn profile_file_paths() {
+        assert_eq!(
+            profile_config_path(Path::new("config/application.yaml"), "prod"),
+            Path::new("config/application-prod.yaml")
+        );
+        assert_eq!(profile_config_path(Path::new("settings"), "dev"), Path::new("settings-dev"));
+    }
+}
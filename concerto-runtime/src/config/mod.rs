@@ -0,0 +1,30 @@
+mod format;
+mod loader;
+mod placeholder;
+
+pub use format::ConfigFormat;
+pub use loader::ConfigLoader;
+pub use placeholder::{resolve_config_value, ResolvedValue, ValueSource};
+pub(crate) use placeholder::{scope_placeholders, trace_placeholders};
+
+use crate::error::ConcertoError;
+use config::{Config, File, FileFormat};
+use std::path::Path;
+
+/// Load config from a specific TOML file
+pub fn load_toml_config<P: AsRef<Path>>(path: P) -> Result<Config, ConcertoError> {
+    let config = Config::builder()
+        .add_source(File::from(path.as_ref()).format(FileFormat::Toml))
+        .add_source(config::Environment::with_prefix("APP").separator("_"))
+        .build()?;
+    Ok(config)
+}
+
+/// Load config from a specific YAML file
+pub fn load_yaml_config<P: AsRef<Path>>(path: P) -> Result<Config, ConcertoError> {
+    let config = Config::builder()
+        .add_source(File::from(path.as_ref()).format(FileFormat::Yaml))
+        .add_source(config::Environment::with_prefix("APP").separator("_"))
+        .build()?;
+    Ok(config)
+}
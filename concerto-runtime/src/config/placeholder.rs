@@ -0,0 +1,405 @@
+use super::loader::DOTENV_KEY;
+use crate::error::ConcertoError;
+use config::{Config, Source};
+use std::borrow::Cow;
+use tracing::warn;
+
+/// Prefix of placeholders that read an environment variable directly
+const ENV_PREFIX: &str = "env:";
+
+/// Where the value of a placeholder came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueSource {
+    /// A config file, with its path
+    File(String),
+    /// An environment variable (including `.env` entries and `${env:VAR}`)
+    Environment,
+    /// A `set_override`/command-line override, or a value set in code
+    Override,
+    /// The default value written in the placeholder
+    Default,
+}
+
+impl std::fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueSource::File(path) => write!(f, "file {}", path),
+            ValueSource::Environment => write!(f, "environment"),
+            ValueSource::Override => write!(f, "override"),
+            ValueSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// A placeholder and the value it resolved to
+#[derive(Debug, Clone)]
+pub struct ResolvedValue {
+    /// Placeholder as written, e.g. `${app.interval:5s}`
+    pub placeholder: String,
+    /// Config key or environment variable name
+    pub key: String,
+    pub value: String,
+    pub source: ValueSource,
+}
+
+/// Expand relative placeholders (`${.key}`) under the given config prefix
+///
+/// `${.interval:5s}` with prefix `tenant_a` becomes `${tenant_a.interval:5s}`.
+/// Escaped placeholders (`\${.key}`) are left alone.
+/// Values are returned unchanged when there is no prefix.
+pub(crate) fn scope_placeholders<'a>(value: &'a str, prefix: Option<&str>) -> Cow<'a, str> {
+    let prefix = match prefix {
+        Some(prefix) if value.contains("${.") => prefix,
+        _ => return Cow::Borrowed(value),
+    };
+
+    let mut scoped = String::with_capacity(value.len() + prefix.len());
+    let mut last = 0;
+    for (index, _) in value.match_indices("${.") {
+        if value[..index].ends_with('\\') {
+            continue;
+        }
+        scoped.push_str(&value[last..index]);
+        scoped.push_str("${");
+        scoped.push_str(prefix);
+        scoped.push('.');
+        last = index + 3;
+    }
+    scoped.push_str(&value[last..]);
+    Cow::Owned(scoped)
+}
+
+/// Resolve config placeholders in a value
+///
+/// Placeholders can appear anywhere in the value, any number of times:
+///
+/// ```text
+/// ${app.interval}                 // whole value from config
+/// 0 ${app.minute} * * * *         // embedded in a cron expression
+/// ${app.interval:5s}              // with a default value
+/// ${app.interval:${app.fallback:5s}}  // nested default
+/// ${env:POLL_INTERVAL:30s}        // environment variable, with a default
+/// \${literal}                     // escaped, resolves to `${literal}`
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a placeholder is unclosed or empty, or if a key is not
+/// found and no default value is provided.
+pub fn resolve_config_value(value: &str, config: &Config) -> Result<String, ConcertoError> {
+    interpolate(value, value, config)
+}
+
+/// Replace every placeholder in `text`, reporting errors against `original`
+fn interpolate(text: &str, original: &str, config: &Config) -> Result<String, ConcertoError> {
+    let mut resolved = String::with_capacity(text.len());
+    let mut literal_start = 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        if rest.starts_with("\\${") {
+            resolved.push_str(&text[literal_start..i]);
+            resolved.push_str("${");
+            i += 3;
+            literal_start = i;
+        } else if rest.starts_with("${") {
+            resolved.push_str(&text[literal_start..i]);
+            let end = find_closing_brace(text, i + 2).ok_or_else(|| {
+                ConcertoError::InvalidPlaceholder(format!(
+                    "Unclosed placeholder in '{}' (missing closing '}}')",
+                    original
+                ))
+            })?;
+            resolved.push_str(&resolve_placeholder(&text[i + 2..end], original, config)?);
+            i = end + 1;
+            literal_start = i;
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    resolved.push_str(&text[literal_start..]);
+    Ok(resolved)
+}
+
+/// Find the `}` closing a placeholder whose content starts at `start`
+///
+/// Works on bytes: the delimiters are ASCII, so `i` may fall inside a multi-byte
+/// character without slicing `text` there.
+fn find_closing_brace(text: &str, start: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 1;
+    let mut i = start;
+
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"\\${") {
+            i += 3;
+        } else if bytes[i..].starts_with(b"${") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i] == b'}' {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+            i += 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    None
+}
+
+/// Split the content of a placeholder into (reads env, key, default)
+fn split_placeholder(inner: &str) -> (bool, &str, Option<&str>) {
+    let (env_var, body) = match inner.strip_prefix(ENV_PREFIX) {
+        Some(body) => (true, body),
+        None => (false, inner),
+    };
+    match body.find(':') {
+        Some(colon_pos) => (env_var, body[..colon_pos].trim(), Some(&body[colon_pos + 1..])),
+        None => (env_var, body.trim(), None),
+    }
+}
+
+/// Environment variable `key`, or the `.env` entry loaded by `ConfigLoader::dotenv`
+fn env_value(key: &str, config: &Config) -> Option<String> {
+    std::env::var(key).ok().or_else(|| {
+        let mut dotenv = config.get_table(DOTENV_KEY).ok()?;
+        dotenv.remove(key)?.into_string().ok()
+    })
+}
+
+/// Resolve the content of a single placeholder (`key`, `key:default`, `env:VAR:default`)
+fn resolve_placeholder(inner: &str, original: &str, config: &Config) -> Result<String, ConcertoError> {
+    let (env_var, key, default_value) = split_placeholder(inner);
+
+    if key.is_empty() {
+        return Err(ConcertoError::InvalidPlaceholder(format!(
+            "Empty placeholder '${{{}}}' in '{}'",
+            inner, original
+        )));
+    }
+
+    if key.starts_with('.') {
+        return Err(ConcertoError::InvalidPlaceholder(format!(
+            "Relative placeholder '${{{}}}' can only be used by instances registered with \
+             `SchedulerBuilder::register_named(instance, \"prefix\")`",
+            inner
+        )));
+    }
+
+    let found = if env_var {
+        env_value(key, config)
+    } else {
+        config.get_string(key).ok()
+    };
+
+    if let Some(resolved) = found {
+        return Ok(resolved);
+    }
+
+    if let Some(default_value) = default_value {
+        warn!(
+            config_key = %key,
+            env = env_var,
+            default_value = %default_value,
+            "Config key not found, using default value"
+        );
+        return interpolate(default_value, original, config);
+    }
+
+    Err(ConcertoError::MissingPlaceholder {
+        key: key.to_string(),
+        env: env_var,
+    })
+}
+
+/// Resolve each top-level placeholder of a value and record where it came from
+///
+/// Placeholders that fail to resolve are left out; resolving the whole value
+/// with [`resolve_config_value`] reports them.
+pub(crate) fn trace_placeholders(value: &str, config: &Config) -> Vec<ResolvedValue> {
+    let mut traced = Vec::new();
+    let mut i = 0;
+
+    while i < value.len() {
+        let rest = &value[i..];
+        if rest.starts_with("\\${") {
+            i += 3;
+        } else if rest.starts_with("${") {
+            let Some(end) = find_closing_brace(value, i + 2) else {
+                break;
+            };
+            let inner = &value[i + 2..end];
+            let (env_var, key, default_value) = split_placeholder(inner);
+            let found = if env_var {
+                env_value(key, config).map(|v| (v, ValueSource::Environment))
+            } else {
+                config.get_string(key).ok().map(|v| (v, value_source(config, key)))
+            };
+            let resolved = found.or_else(|| {
+                let default_value = interpolate(default_value?, value, config).ok()?;
+                Some((default_value, ValueSource::Default))
+            });
+            if let Some((resolved, source)) = resolved {
+                traced.push(ResolvedValue {
+                    placeholder: value[i..=end].to_string(),
+                    key: key.to_string(),
+                    value: resolved,
+                    source,
+                });
+            }
+            i = end + 1;
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    traced
+}
+
+/// Source of a config key, from the origin the config crate keeps on each value
+fn value_source(config: &Config, key: &str) -> ValueSource {
+    let table = match key.rsplit_once('.') {
+        Some((parent, _)) => config.get_table(parent).ok(),
+        None => config.collect().ok(),
+    };
+    let name = key.rsplit('.').next().unwrap_or(key);
+    let origin = table.and_then(|table| table.get(name).and_then(|v| v.origin().map(str::to_string)));
+
+    match origin.as_deref() {
+        None => ValueSource::Override,
+        Some("the environment") => ValueSource::Environment,
+        Some(path) => ValueSource::File(path.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let mut dotenv = config::Map::new();
+        dotenv.insert("FROM_DOTENV".to_string(), "dotenv-value".to_string());
+        Config::builder()
+            .set_override("app.interval", "30s")
+            .unwrap()
+            .set_override("app.minute", "15")
+            .unwrap()
+            .set_override("app.fallback", "10s")
+            .unwrap()
+            .set_override(DOTENV_KEY, dotenv)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    fn resolve(value: &str) -> Result<String, ConcertoError> {
+        resolve_config_value(value, &config())
+    }
+
+    #[test]
+    fn whole_embedded_and_repeated_placeholders() {
+        assert_eq!(resolve("${app.interval}").unwrap(), "30s");
+        assert_eq!(resolve("0 ${app.minute} * * * *").unwrap(), "0 15 * * * *");
+        assert_eq!(resolve("${app.minute}-${app.minute}").unwrap(), "15-15");
+        assert_eq!(resolve("no placeholders").unwrap(), "no placeholders");
+        assert_eq!(resolve("${ app.interval }").unwrap(), "30s");
+    }
+
+    #[test]
+    fn defaults_apply_only_to_missing_keys() {
+        assert_eq!(resolve("${app.interval:5s}").unwrap(), "30s");
+        assert_eq!(resolve("${app.missing:5s}").unwrap(), "5s");
+        assert_eq!(resolve("${app.missing:}").unwrap(), "");
+        // Everything after the first colon is the default
+        assert_eq!(resolve("${app.missing:http://host:80}").unwrap(), "http://host:80");
+        assert_eq!(resolve("${app.missing:${app.fallback:5s}}").unwrap(), "10s");
+        assert_eq!(resolve("${app.missing:${app.other:5s}}").unwrap(), "5s");
+        assert_eq!(resolve("${app.missing:a ${app.minute} b}").unwrap(), "a 15 b");
+    }
+
+    #[test]
+    fn environment_placeholders() {
+        assert_eq!(resolve("${env:PATH}").unwrap(), std::env::var("PATH").unwrap());
+        assert_eq!(resolve("${env:FROM_DOTENV}").unwrap(), "dotenv-value");
+        assert_eq!(resolve("${env:CONCERTO_TEST_UNSET:7s}").unwrap(), "7s");
+        assert!(matches!(
+            resolve("${env:CONCERTO_TEST_UNSET}"),
+            Err(ConcertoError::MissingPlaceholder { env: true, .. })
+        ));
+        // A config key is not an environment variable, and the other way round
+        assert!(resolve("${env:app.interval}").is_err());
+        assert!(resolve("${FROM_DOTENV}").is_err());
+    }
+
+    #[test]
+    fn escaped_placeholders_stay_literal() {
+        assert_eq!(resolve("\\${app.interval}").unwrap(), "${app.interval}");
+        assert_eq!(resolve("${app.interval} \\${x}").unwrap(), "30s ${x}");
+        assert_eq!(resolve("${app.missing:\\${x}}").unwrap(), "${x}");
+        assert_eq!(resolve("\\$ and $ alone").unwrap(), "\\$ and $ alone");
+    }
+
+    #[test]
+    fn malformed_placeholders_are_errors() {
+        assert!(matches!(resolve("${app.interval"), Err(ConcertoError::InvalidPlaceholder(_))));
+        assert!(matches!(resolve("${app.missing:${app.fallback}"), Err(ConcertoError::InvalidPlaceholder(_))));
+        assert!(matches!(resolve("${}"), Err(ConcertoError::InvalidPlaceholder(_))));
+        assert!(matches!(resolve("${:5s}"), Err(ConcertoError::InvalidPlaceholder(_))));
+        assert!(matches!(resolve("${.interval}"), Err(ConcertoError::InvalidPlaceholder(_))));
+        assert!(matches!(
+            resolve("${app.missing}"),
+            Err(ConcertoError::MissingPlaceholder { env: false, .. })
+        ));
+    }
+
+    #[test]
+    fn non_ascii_keys_and_defaults() {
+        assert_eq!(resolve("${app.label:héllo}").unwrap(), "héllo");
+        assert_eq!(resolve("${app.missing:${app.ünïcode:5s}} wörld").unwrap(), "5s wörld");
+        assert_eq!(resolve("0 ${app.minüte:5} * * * *").unwrap(), "0 5 * * * *");
+        assert!(matches!(resolve("${app.clé}"), Err(ConcertoError::MissingPlaceholder { .. })));
+        assert!(matches!(resolve("${app.label:héllo"), Err(ConcertoError::InvalidPlaceholder(_))));
+        let traced = trace_placeholders("é ${app.label:héllo} ${app.interval}", &config());
+        assert_eq!(traced.len(), 2);
+        assert_eq!(traced[0].placeholder, "${app.label:héllo}");
+    }
+
+    include!("placeholder_syntax.rs");
+
+    #[test]
+    fn shared_syntax_cases() {
+        for (value, well_formed) in PLACEHOLDER_SYNTAX_CASES {
+            let malformed = matches!(resolve(value), Err(ConcertoError::InvalidPlaceholder(_)));
+            assert_eq!(!malformed, *well_formed, "{}", value);
+        }
+    }
+
+    #[test]
+    fn relative_placeholders_are_scoped() {
+        assert_eq!(scope_placeholders("${.interval:5s}", Some("tenant")), "${tenant.interval:5s}");
+        assert_eq!(scope_placeholders("${.a} ${.b}", Some("t")), "${t.a} ${t.b}");
+        assert_eq!(scope_placeholders("\\${.a} ${app.x}", Some("t")), "\\${.a} ${app.x}");
+        assert!(matches!(scope_placeholders("${.a}", None), Cow::Borrowed("${.a}")));
+    }
+
+    #[test]
+    fn traced_values_record_their_source() {
+        let value = "${app.interval} ${app.missing:5s} ${env:FROM_DOTENV} ${app.nope}";
+        let traced = trace_placeholders(value, &config());
+        let sources: Vec<_> = traced.iter().map(|value| (value.key.as_str(), &value.source)).collect();
+        assert_eq!(
+            sources,
+            [
+                ("app.interval", &ValueSource::Override),
+                ("app.missing", &ValueSource::Default),
+                ("FROM_DOTENV", &ValueSource::Environment),
+            ]
+        );
+        assert!(trace_placeholders("\\${app.interval}", &config()).is_empty());
+    }
+}
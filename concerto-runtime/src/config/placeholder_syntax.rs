@@ -0,0 +1,26 @@
+// Placeholder syntax shared by the runtime resolver and the compile-time check of
+// `#[scheduled]`: each value and whether its placeholders are well formed.
+// Included by the tests of both crates so their grammars cannot drift apart.
+const PLACEHOLDER_SYNTAX_CASES: &[(&str, bool)] = &[
+    ("plain text", true),
+    ("${app.interval}", true),
+    ("${app.interval:5s}", true),
+    ("0 ${app.minute:5} * * * *", true),
+    ("${app.a:${app.b:10s}}", true),
+    ("${env:POLL_INTERVAL:30s}", true),
+    ("\\${literal}", true),
+    ("${app.missing:http://host:80}", true),
+    ("${app.label:héllo}", true),
+    ("0 ${app.minüte:5} * * * *", true),
+    ("${app.clé:${app.ünïcode:5s}}", true),
+    ("héllo ${app.interval:5s} wörld", true),
+    ("${app.interval", false),
+    ("${app.a:${app.b:5s}", false),
+    ("héllo ${", false),
+    ("${app.label:héllo", false),
+    ("${}", false),
+    ("${ }", false),
+    ("${env:}", false),
+    ("${:5s}", false),
+    ("${app.ü:${}}", false),
+];
@@ -0,0 +1,187 @@
+use crate::scheduler::StartReport;
+use crate::task::TaskSource;
+use std::fmt;
+use tokio_cron_scheduler::JobSchedulerError;
+
+/// Errors returned by Concerto
+///
+/// Match on the variant to decide whether to crash or degrade, e.g. keep running
+/// with the previous config when a reload fails with [`ConcertoError::ConfigLoad`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConcertoError {
+    /// A config file, `.env` file or other config source could not be read or parsed
+    ConfigLoad(String),
+    /// A placeholder references a config key or environment variable that is not set
+    /// and has no default value
+    MissingPlaceholder {
+        /// Config key, or variable name for `${env:VAR}`
+        key: String,
+        /// Whether the placeholder reads an environment variable
+        env: bool,
+    },
+    /// A placeholder is malformed (unclosed, empty, or relative outside `register_named`)
+    InvalidPlaceholder(String),
+    /// An `enabled` value or expression could not be evaluated
+    InvalidEnabled(String),
+    /// A cron expression could not be parsed
+    InvalidCron { expression: String },
+    /// An interval (`fixed_rate`/`fixed_delay`) value could not be parsed
+    InvalidInterval { value: String },
+    /// A one-shot time that is not an RFC 3339 timestamp
+    InvalidTimestamp { value: String },
+    /// A schedule type other than `cron`, `fixed_rate` or `fixed_delay`
+    UnknownScheduleType(String),
+    /// A zone that is neither `local`, `UTC` nor a known IANA name
+    InvalidZone(String),
+    /// Several tasks share a name, with the source locations of each
+    DuplicateTaskNames(Vec<(String, Vec<TaskSource>)>),
+    /// `reload_config` was called on a scheduler not created from config files
+    ReloadUnavailable,
+    /// No task with this name is known to the scheduler
+    UnknownTask(String),
+    /// The task is known but not scheduled (disabled, or it failed to register)
+    TaskNotScheduled(String),
+    /// A schedule that cannot be applied to a running task, such as `once`
+    UnsupportedSchedule(String),
+    /// The config was reloaded, but some tasks could not be updated and kept their previous schedule
+    ReloadIncomplete(Vec<(String, ConcertoError)>),
+    /// `fail_fast` is enabled and some tasks could not be registered; no task was left running
+    StartFailed(StartReport),
+    /// A signal handler could not be installed
+    Signal(std::io::Error),
+    /// The admin server could not listen on its address
+    Listen { address: String, source: std::io::Error },
+    /// The underlying scheduler engine failed
+    Engine(JobSchedulerError),
+}
+
+impl fmt::Display for ConcertoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConcertoError::ConfigLoad(message) => write!(f, "{}", message),
+            ConcertoError::MissingPlaceholder { key, env: true } => write!(
+                f,
+                "Environment variable '{}' is not set and no default value provided.\n\
+                 \n\
+                 To fix this error, either set the variable or provide a default value:\n\
+                    ${{env:{}:default_value}}",
+                key, key
+            ),
+            ConcertoError::MissingPlaceholder { key, env: false } => write!(
+                f,
+                "Config key '{}' not found and no default value provided.\n\
+                 \n\
+                 To fix this error, either:\n\
+                 1. Add the key to your config file:\n\
+                    [app]\n\
+                    {} = \"value\"\n\
+                 \n\
+                 2. Or provide a default value in the placeholder:\n\
+                    ${{{}:default_value}}\n\
+                 \n\
+                 Example: ${{{0}:5s}} for a 5 second default",
+                key,
+                key.split('.').next_back().unwrap_or(key),
+                key
+            ),
+            ConcertoError::InvalidPlaceholder(message) => write!(f, "{}", message),
+            ConcertoError::InvalidEnabled(message) => write!(f, "{}", message),
+            ConcertoError::InvalidCron { expression } => write!(
+                f,
+                "Invalid cron expression '{}' (expected 'second minute hour day month weekday')",
+                expression
+            ),
+            ConcertoError::InvalidInterval { value } => write!(f, "Invalid interval value: {}", value),
+            ConcertoError::InvalidTimestamp { value } => write!(
+                f,
+                "Invalid timestamp '{}' (expected RFC 3339, e.g. '2025-06-01T09:00:00+07:00')",
+                value
+            ),
+            ConcertoError::UnknownScheduleType(schedule_type) => {
+                write!(f, "Unknown schedule type: {}", schedule_type)
+            }
+            ConcertoError::InvalidZone(zone) => write!(
+                f,
+                "Invalid zone '{}' (expected 'local', 'UTC' or an IANA name like 'Asia/Jakarta')",
+                zone
+            ),
+            ConcertoError::DuplicateTaskNames(duplicates) => {
+                writeln!(f, "Duplicate task names found.")?;
+                writeln!(f)?;
+                for (name, sources) in duplicates {
+                    writeln!(f, "Task name '{}' is used {} times:", name, sources.len())?;
+                    for source in sources {
+                        writeln!(f, "  - {}", source)?;
+                    }
+                }
+                write!(
+                    f,
+                    "\n\
+                     To fix this error, either:\n\
+                     1. Give the task an explicit name: #[scheduled(name = \"billing::cleanup\", ...)]\n\
+                     2. Register instances of the same type with distinct names:\n\
+                        .register_named(instance, \"tenant_a\")"
+                )
+            }
+            ConcertoError::ReloadUnavailable => write!(
+                f,
+                "Config was not loaded from files, nothing to reload \
+                 (create the builder with with_toml, with_yaml or with_loader)"
+            ),
+            ConcertoError::UnknownTask(name) => write!(f, "Unknown task '{}'", name),
+            ConcertoError::TaskNotScheduled(name) => write!(
+                f,
+                "Task '{}' is not scheduled (disabled, or it failed to register)",
+                name
+            ),
+            ConcertoError::UnsupportedSchedule(schedule) => {
+                write!(f, "Schedule '{}' cannot be applied to a running task", schedule)
+            }
+            ConcertoError::ReloadIncomplete(failures) => {
+                write!(f, "Config reloaded, but {} task(s) could not be updated:", failures.len())?;
+                for (name, error) in failures {
+                    write!(f, "\n  - {}: {}", name, error)?;
+                }
+                Ok(())
+            }
+            ConcertoError::StartFailed(report) => {
+                write!(
+                    f,
+                    "Scheduler start aborted (fail_fast), {} task(s) could not be registered:",
+                    report.failed.len()
+                )?;
+                for task in &report.failed {
+                    write!(f, "\n  - {}: {}", task.name, task.error)?;
+                }
+                Ok(())
+            }
+            ConcertoError::Signal(e) => write!(f, "Failed to install signal handler: {}", e),
+            ConcertoError::Listen { address, source } => write!(f, "Failed to listen on {}: {}", address, source),
+            ConcertoError::Engine(e) => write!(f, "Scheduler engine error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ConcertoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConcertoError::Signal(e) => Some(e),
+            ConcertoError::Listen { source, .. } => Some(source),
+            ConcertoError::Engine(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<config::ConfigError> for ConcertoError {
+    fn from(e: config::ConfigError) -> Self {
+        ConcertoError::ConfigLoad(e.to_string())
+    }
+}
+
+impl From<JobSchedulerError> for ConcertoError {
+    fn from(e: JobSchedulerError) -> Self {
+        ConcertoError::Engine(e)
+    }
+}
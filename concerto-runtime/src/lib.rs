@@ -2,18 +2,43 @@
 //! 
 //! This crate provides the runtime infrastructure for executing scheduled tasks.
 
+#[cfg(any(feature = "admin-http", all(feature = "control-socket", unix)))]
+mod admin;
+mod condition;
 mod config;
+mod error;
+mod profile;
 mod registry;
+mod schedule;
 mod time_unit;
 pub mod runnable;
 pub mod scheduler;
 pub mod task;
 
 // Re-export public API
-pub use config::{load_toml_config, load_yaml_config};
+pub use condition::TaskCondition;
+pub use ::config::Config;
+pub use config::{
+    load_toml_config, load_yaml_config, ConfigFormat, ConfigLoader, ResolvedValue, ValueSource,
+};
+pub use error::ConcertoError;
 pub use linkme;
+pub use profile::{ACTIVE_PROFILES_ENV, ACTIVE_PROFILES_KEY};
 pub use registry::SCHEDULED_TASKS;
+pub use schedule::Schedule;
 pub use runnable::{Runnable, RunnableTask, ScheduledMetadata, RUNNABLE_TASKS};
-pub use scheduler::{Scheduler, SchedulerBuilder, SchedulerHandle, ScheduledInstance};
-pub use task::{ScheduledTask, ScheduledMethodMetadata};
+pub use scheduler::{
+    DisabledTask, FailedTask, HealthReport, HealthStatus, RegisteredTask, Scheduler,
+    SchedulerBuilder, SchedulerEvent, SchedulerHandle, ScheduledInstance, SpanLayout, StartReport,
+    TaskExecution, TaskInfo, TaskKind, TaskListener, TaskOutcome, TaskState, TaskValidation,
+    ValidationOutcome, ValidationReport,
+};
+#[cfg(feature = "metrics")]
+pub use scheduler::OPENMETRICS_CONTENT_TYPE;
+#[cfg(feature = "admin-http")]
+pub use admin::AdminHttp;
+pub use task::{
+    IntoTaskResult, ScheduledMethodMetadata, ScheduledTask, TaskFuture, TaskMetadata, TaskResult,
+    TaskSource,
+};
 pub use time_unit::TimeUnit;
\ No newline at end of file
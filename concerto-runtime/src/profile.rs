@@ -0,0 +1,58 @@
+use config::Config;
+
+/// Config key holding the active profiles (e.g. `concerto.profiles.active = "prod,eu"`)
+pub const ACTIVE_PROFILES_KEY: &str = "concerto.profiles.active";
+
+/// Environment variable holding the active profiles (e.g. `CONCERTO_PROFILES_ACTIVE=prod,eu`)
+pub const ACTIVE_PROFILES_ENV: &str = "CONCERTO_PROFILES_ACTIVE";
+
+/// Split a comma-separated profile list, dropping empty entries
+pub(crate) fn split_profiles(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|profile| !profile.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Read active profiles from the environment variable or the config
+///
+/// The environment variable takes precedence over the config key.
+/// The config key accepts either a comma-separated string or a list.
+pub(crate) fn active_profiles_from(config: &Config) -> Vec<String> {
+    if let Ok(value) = std::env::var(ACTIVE_PROFILES_ENV) {
+        return split_profiles(&value);
+    }
+
+    if let Ok(value) = config.get_string(ACTIVE_PROFILES_KEY) {
+        return split_profiles(&value);
+    }
+
+    config
+        .get_array(ACTIVE_PROFILES_KEY)
+        .map(|values| {
+            values
+                .into_iter()
+                .filter_map(|value| value.into_string().ok())
+                .flat_map(|value| split_profiles(&value))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Check if a task declared for `task_profiles` should run with `active_profiles`
+///
+/// - Tasks without profiles are always active
+/// - `"prod"` matches when the `prod` profile is active
+/// - `"!prod"` matches when the `prod` profile is NOT active
+pub(crate) fn profiles_match(task_profiles: &[&str], active_profiles: &[String]) -> bool {
+    if task_profiles.is_empty() {
+        return true;
+    }
+
+    task_profiles.iter().any(|profile| match profile.strip_prefix('!') {
+        Some(negated) => !active_profiles.iter().any(|active| active == negated),
+        None => active_profiles.iter().any(|active| active == profile),
+    })
+}
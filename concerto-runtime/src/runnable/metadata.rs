@@ -1,3 +1,4 @@
+use crate::condition::TaskCondition;
 use crate::time_unit::TimeUnit;
 
 /// Trait for extracting scheduling metadata from types annotated with #[scheduled]
@@ -8,6 +9,51 @@ pub trait ScheduledMetadata {
     fn enabled() -> &'static str;
     fn time_unit() -> &'static str;
     fn zone() -> &'static str;
+
+    /// Explicit task name from `#[scheduled(name = "...")]`
+    fn name() -> Option<&'static str> {
+        None
+    }
+
+    /// Predicate evaluated at start to decide whether the task is registered
+    fn condition() -> Option<TaskCondition> {
+        None
+    }
+
+    /// Tags used to select tasks with `SchedulerBuilder::include_tags`/`exclude_tags`
+    fn tags() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Profiles in which the task is active (empty = always active)
+    fn profiles() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Running time after which a run is reported as stuck, empty for the scheduler default
+    fn warn_after() -> &'static str {
+        ""
+    }
+
+    /// Longest time without a successful run before the task is overdue, empty for no limit
+    fn expect_success_within() -> &'static str {
+        ""
+    }
+
+    /// Running time after which a run is cancelled, empty for no limit
+    fn timeout() -> &'static str {
+        ""
+    }
+
+    /// Human readable description of the task
+    fn description() -> &'static str {
+        ""
+    }
+
+    /// Team or person responsible for the task
+    fn owner() -> &'static str {
+        ""
+    }
     
     /// Get the TimeUnit enum (used when time_unit is specified as TimeUnit::*)
     /// Returns None if time_unit was not explicitly set (uses default from string)
@@ -6,7 +6,7 @@
 /// # Example
 /// 
 /// ```rust
-/// use scheduled_runtime::Runnable;
+/// use concerto_runtime::Runnable;
 /// 
 /// struct MyTask {
 ///     name: String,
@@ -0,0 +1,338 @@
+use crate::error::ConcertoError;
+use crate::time_unit::TimeUnit;
+use chrono::{DateTime, TimeZone, Timelike, Utc};
+use croner::parser::{CronParser, Seconds};
+use croner::Cron;
+use std::fmt;
+use std::time::Duration;
+
+/// A task schedule that can be previewed without running a scheduler
+///
+/// Useful to answer "when will this run next?" in tooling and tests.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use concerto_runtime::Schedule;
+///
+/// let from = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+///
+/// let daily = Schedule::cron("0 0 9 * * *", "Asia/Jakarta").unwrap();
+/// let next = daily.upcoming(from, 2);
+/// assert_eq!(next[0], Utc.with_ymd_and_hms(2025, 1, 1, 2, 0, 0).unwrap());
+/// assert_eq!(next[1], Utc.with_ymd_and_hms(2025, 1, 2, 2, 0, 0).unwrap());
+///
+/// // For fixed_rate/fixed_delay, `from` is the last run
+/// let every_30s = Schedule::parse("fixed_rate", "30s").unwrap();
+/// assert_eq!(every_30s.upcoming(from, 1)[0], from + chrono::Duration::seconds(30));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    kind: ScheduleKind,
+}
+
+#[derive(Debug, Clone)]
+enum ScheduleKind {
+    Cron { expression: String, cron: Box<Cron>, zone: Zone },
+    FixedRate { interval: Duration },
+    FixedDelay { interval: Duration },
+    Once { at: DateTime<Utc> },
+}
+
+/// Zone of a cron schedule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Zone {
+    Local,
+    Utc,
+    Named(chrono_tz::Tz),
+}
+
+impl Zone {
+    /// Parse `local`, `UTC` (case-insensitive) or an IANA name like `Asia/Jakarta`
+    pub(crate) fn parse(zone: &str) -> Result<Self, ConcertoError> {
+        match zone.to_lowercase().as_str() {
+            "local" => Ok(Zone::Local),
+            "utc" => Ok(Zone::Utc),
+            _ => zone
+                .parse()
+                .map(Zone::Named)
+                .map_err(|_| ConcertoError::InvalidZone(zone.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Zone::Local => write!(f, "Local"),
+            Zone::Utc => write!(f, "UTC"),
+            Zone::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+impl Schedule {
+    /// Parse a schedule the way `#[scheduled]` attributes are written
+    ///
+    /// | `schedule_type` | `value` |
+    /// |-----------------|---------|
+    /// | `cron`          | six-field cron expression, in UTC |
+    /// | `fixed_rate`    | interval like `30s`, `5m` or milliseconds |
+    /// | `fixed_delay`   | interval like `30s`, `5m` or milliseconds |
+    /// | `once`          | RFC 3339 timestamp like `2025-06-01T09:00:00+07:00` |
+    ///
+    /// Use [`Schedule::cron`] for cron expressions in another zone.
+    pub fn parse(schedule_type: &str, value: &str) -> Result<Self, ConcertoError> {
+        match schedule_type {
+            "cron" => Self::cron(value, "UTC"),
+            "fixed_rate" => Ok(Self::fixed_rate(parse_interval(value)?)),
+            "fixed_delay" => Ok(Self::fixed_delay(parse_interval(value)?)),
+            "once" => DateTime::parse_from_rfc3339(value.trim())
+                .map(|at| Self::once(at.with_timezone(&Utc)))
+                .map_err(|_| ConcertoError::InvalidTimestamp {
+                    value: value.to_string(),
+                }),
+            _ => Err(ConcertoError::UnknownScheduleType(schedule_type.to_string())),
+        }
+    }
+
+    /// Cron schedule firing in `zone` (`local`, `UTC` or an IANA name)
+    pub fn cron(expression: &str, zone: &str) -> Result<Self, ConcertoError> {
+        let zone = Zone::parse(zone)?;
+        let cron = parse_cron(expression)?;
+        Ok(Self {
+            kind: ScheduleKind::Cron {
+                expression: expression.to_string(),
+                cron: Box::new(cron),
+                zone,
+            },
+        })
+    }
+
+    /// Runs every `interval`, measured between run starts
+    pub fn fixed_rate(interval: Duration) -> Self {
+        Self { kind: ScheduleKind::FixedRate { interval } }
+    }
+
+    /// Runs `interval` after the previous run completed
+    pub fn fixed_delay(interval: Duration) -> Self {
+        Self { kind: ScheduleKind::FixedDelay { interval } }
+    }
+
+    /// Runs a single time at `at`
+    pub fn once(at: DateTime<Utc>) -> Self {
+        Self { kind: ScheduleKind::Once { at } }
+    }
+
+    /// The next `n` fire times strictly after `from`
+    ///
+    /// For `fixed_rate` and `fixed_delay`, `from` is the last run (its start for
+    /// `fixed_rate`, its end for `fixed_delay`) and later runs are assumed to take
+    /// no time. A `once` schedule yields at most one time.
+    pub fn upcoming(&self, from: DateTime<Utc>, n: usize) -> Vec<DateTime<Utc>> {
+        match &self.kind {
+            ScheduleKind::Cron { cron, zone, .. } => match zone {
+                Zone::Local => cron_upcoming(cron, from.with_timezone(&chrono::Local), n),
+                Zone::Utc => cron_upcoming(cron, from, n),
+                Zone::Named(tz) => cron_upcoming(cron, from.with_timezone(tz), n),
+            },
+            ScheduleKind::FixedRate { interval } | ScheduleKind::FixedDelay { interval } => {
+                let Ok(step) = chrono::Duration::from_std(*interval) else {
+                    return Vec::new();
+                };
+                if step.is_zero() {
+                    return Vec::new();
+                }
+                std::iter::successors(from.checked_add_signed(step), |last| last.checked_add_signed(step))
+                    .take(n)
+                    .collect()
+            }
+            ScheduleKind::Once { at } => std::iter::once(*at).filter(|at| *at > from).take(n).collect(),
+        }
+    }
+
+    /// Latest cron fire time at or before `at`; `None` for other schedules
+    pub(crate) fn last_fire_time(&self, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let ScheduleKind::Cron { cron, zone, .. } = &self.kind else {
+            return None;
+        };
+        let at = at.with_nanosecond(0).unwrap_or(at);
+        match zone {
+            Zone::Local => cron_previous(cron, at.with_timezone(&chrono::Local)),
+            Zone::Utc => cron_previous(cron, at),
+            Zone::Named(tz) => cron_previous(cron, at.with_timezone(tz)),
+        }
+    }
+
+    /// Expression and zone of a cron schedule
+    pub(crate) fn cron_parts(&self) -> Option<(&str, Zone)> {
+        match &self.kind {
+            ScheduleKind::Cron { expression, zone, .. } => Some((expression, *zone)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_fixed_delay(&self) -> bool {
+        matches!(self.kind, ScheduleKind::FixedDelay { .. })
+    }
+
+    /// Interval of a `fixed_rate` or `fixed_delay` schedule
+    pub(crate) fn interval(&self) -> Option<Duration> {
+        match &self.kind {
+            ScheduleKind::FixedRate { interval } | ScheduleKind::FixedDelay { interval } => Some(*interval),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ScheduleKind::Cron { expression, zone, .. } => write!(f, "cron '{}' ({})", expression, zone),
+            ScheduleKind::FixedRate { interval } => write!(f, "fixed_rate {}ms", interval.as_millis()),
+            ScheduleKind::FixedDelay { interval } => write!(f, "fixed_delay {}ms", interval.as_millis()),
+            ScheduleKind::Once { at } => write!(f, "once at {}", at.to_rfc3339()),
+        }
+    }
+}
+
+/// Parse a six-field cron expression with the same rules as the scheduler engine
+fn parse_cron(expression: &str) -> Result<Cron, ConcertoError> {
+    let invalid = || ConcertoError::InvalidCron {
+        expression: expression.to_string(),
+    };
+    let expression = tokio_cron_scheduler::Job::schedule_to_cron(expression).map_err(|_| invalid())?;
+    CronParser::builder()
+        .seconds(Seconds::Required)
+        .dom_and_dow(true)
+        .build()
+        .parse(&expression)
+        .map_err(|_| invalid())
+}
+
+fn cron_upcoming<Tz>(cron: &Cron, from: DateTime<Tz>, n: usize) -> Vec<DateTime<Utc>>
+where
+    Tz: TimeZone + Copy,
+{
+    // Cron fields have second precision; drop the fraction so times land on whole seconds
+    let from = from.with_nanosecond(0).unwrap_or(from);
+    cron.iter_after(from).take(n).map(|at| at.with_timezone(&Utc)).collect()
+}
+
+fn cron_previous<Tz: TimeZone>(cron: &Cron, at: DateTime<Tz>) -> Option<DateTime<Utc>> {
+    cron.find_previous_occurrence(&at, true).ok().map(|at| at.with_timezone(&Utc))
+}
+
+/// Parse `30s`, `5m`, ... or a plain number of milliseconds; zero is rejected
+fn parse_interval(value: &str) -> Result<Duration, ConcertoError> {
+    let millis = match TimeUnit::parse_duration(value) {
+        Some((amount, unit)) => unit.to_millis(amount),
+        None => value.trim().parse().map_err(|_| ConcertoError::InvalidInterval {
+            value: value.to_string(),
+        })?,
+    };
+    if millis == 0 {
+        return Err(ConcertoError::InvalidInterval { value: value.to_string() });
+    }
+    Ok(Duration::from_millis(millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, s).unwrap()
+    }
+
+    #[test]
+    fn cron_in_a_named_zone_follows_daylight_saving() {
+        // 09:00 in New York is 14:00 UTC in winter and 13:00 UTC from 9 March 2025
+        let daily = Schedule::cron("0 0 9 * * *", "America/New_York").unwrap();
+        assert_eq!(
+            daily.upcoming(utc(2025, 3, 7, 15, 0, 0), 3),
+            [utc(2025, 3, 8, 14, 0, 0), utc(2025, 3, 9, 13, 0, 0), utc(2025, 3, 10, 13, 0, 0)]
+        );
+        assert_eq!(daily.to_string(), "cron '0 0 9 * * *' (America/New_York)");
+    }
+
+    #[test]
+    fn cron_around_skipped_and_repeated_hours() {
+        // 02:30 does not exist on 9 March: the run moves to 03:00 EDT
+        let spring = Schedule::cron("0 30 2 * * *", "America/New_York").unwrap();
+        assert_eq!(
+            spring.upcoming(utc(2025, 3, 8, 12, 0, 0), 2),
+            [utc(2025, 3, 9, 7, 0, 0), utc(2025, 3, 10, 6, 30, 0)]
+        );
+        // 01:30 happens twice on 2 November: the task runs once, at the first one
+        let fall = Schedule::cron("0 30 1 * * *", "America/New_York").unwrap();
+        assert_eq!(
+            fall.upcoming(utc(2025, 11, 1, 12, 0, 0), 2),
+            [utc(2025, 11, 2, 5, 30, 0), utc(2025, 11, 3, 6, 30, 0)]
+        );
+        assert_eq!(fall.last_fire_time(utc(2025, 11, 2, 7, 0, 0)), Some(utc(2025, 11, 2, 5, 30, 0)));
+    }
+
+    #[test]
+    fn cron_fire_times_are_strictly_after_from() {
+        let every_minute = Schedule::parse("cron", "0 * * * * *").unwrap();
+        let from = utc(2025, 1, 1, 0, 0, 0);
+        assert_eq!(every_minute.upcoming(from, 1), [utc(2025, 1, 1, 0, 1, 0)]);
+        // Fractions of a second are dropped
+        let from = from + chrono::Duration::milliseconds(1500);
+        assert_eq!(every_minute.upcoming(from, 1), [utc(2025, 1, 1, 0, 1, 0)]);
+        assert_eq!(every_minute.last_fire_time(from), Some(utc(2025, 1, 1, 0, 0, 0)));
+        assert_eq!(every_minute.to_string(), "cron '0 * * * * *' (UTC)");
+    }
+
+    #[test]
+    fn intervals_count_from_the_last_run() {
+        let from = utc(2025, 1, 1, 0, 0, 0);
+        let rate = Schedule::parse("fixed_rate", "30s").unwrap();
+        assert_eq!(
+            rate.upcoming(from, 3),
+            [utc(2025, 1, 1, 0, 0, 30), utc(2025, 1, 1, 0, 1, 0), utc(2025, 1, 1, 0, 1, 30)]
+        );
+        let delay = Schedule::parse("fixed_delay", "1500").unwrap();
+        assert!(delay.is_fixed_delay());
+        assert_eq!(delay.interval(), Some(Duration::from_millis(1500)));
+        assert_eq!(
+            delay.upcoming(from, 2),
+            [from + chrono::Duration::milliseconds(1500), from + chrono::Duration::milliseconds(3000)]
+        );
+        assert_eq!(rate.last_fire_time(from), None);
+    }
+
+    #[test]
+    fn once_fires_only_in_the_future() {
+        let once = Schedule::parse("once", "2025-06-01T09:00:00+07:00").unwrap();
+        let at = utc(2025, 6, 1, 2, 0, 0);
+        assert_eq!(once.upcoming(at - chrono::Duration::seconds(1), 3), [at]);
+        assert!(once.upcoming(at, 3).is_empty());
+        assert!(once.upcoming(utc(2026, 1, 1, 0, 0, 0), 1).is_empty());
+        assert_eq!(once.last_fire_time(at), None);
+    }
+
+    #[test]
+    fn zero_fire_times_requested() {
+        let from = utc(2025, 1, 1, 0, 0, 0);
+        for schedule in [
+            Schedule::cron("0 * * * * *", "Asia/Jakarta").unwrap(),
+            Schedule::fixed_rate(Duration::from_secs(1)),
+            Schedule::fixed_delay(Duration::from_secs(1)),
+            Schedule::once(from + chrono::Duration::hours(1)),
+        ] {
+            assert!(schedule.upcoming(from, 0).is_empty(), "{}", schedule);
+        }
+    }
+
+    #[test]
+    fn invalid_schedules_are_rejected() {
+        assert!(matches!(Schedule::cron("0 0 9 * * *", "Mars/Olympus"), Err(ConcertoError::InvalidZone(_))));
+        assert!(matches!(Schedule::parse("cron", "every day"), Err(ConcertoError::InvalidCron { .. })));
+        assert!(matches!(Schedule::parse("fixed_rate", "0s"), Err(ConcertoError::InvalidInterval { .. })));
+        assert!(matches!(Schedule::parse("once", "tomorrow"), Err(ConcertoError::InvalidTimestamp { .. })));
+        assert!(matches!(Schedule::parse("hourly", "1"), Err(ConcertoError::UnknownScheduleType(_))));
+    }
+}
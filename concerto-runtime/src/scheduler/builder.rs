@@ -1,16 +1,49 @@
 use super::instance::{RegisteredInstance, ScheduledInstance};
+use super::listener::TaskListener;
+use super::span::SpanLayout;
 use super::scheduler::Scheduler;
-use crate::config::{load_toml_config, load_yaml_config};
+#[cfg(feature = "admin-http")]
+use crate::admin::AdminHttp;
+use crate::config::{ConfigFormat, ConfigLoader};
+use crate::error::ConcertoError;
+use crate::profile::active_profiles_from;
 use crate::runnable::RunnableTask;
+use crate::task::{TaskFuture, TaskMetadata, TaskSource};
 use config::Config;
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use tracing::info;
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// History entries kept per task unless `history_size` is set
+const DEFAULT_HISTORY_SIZE: usize = 10;
+
+/// Events buffered per `subscribe()` receiver before it starts lagging
+const EVENT_CAPACITY: usize = 256;
 
 /// Builder for the scheduler
 pub struct SchedulerBuilder {
     pub(crate) config: Arc<Config>,
     pub(crate) runnable_tasks: Vec<RunnableTask>,
     pub(crate) registered_instances: Vec<RegisteredInstance>,
+    pub(crate) include_tags: Vec<String>,
+    pub(crate) exclude_tags: Vec<String>,
+    pub(crate) config_loader: Option<ConfigLoader>,
+    pub(crate) active_profiles: Option<Vec<String>>,
+    /// Profiles set by `active_profiles` whose files are layered over the config in `try_build`
+    pub(crate) relayer_profiles: bool,
+    pub(crate) watch_config: Option<Duration>,
+    pub(crate) reload_on_sighup: bool,
+    pub(crate) fail_fast: bool,
+    pub(crate) history_size: usize,
+    pub(crate) listeners: Vec<Arc<dyn TaskListener>>,
+    pub(crate) span_layout: SpanLayout,
+    pub(crate) lateness_warning: Option<Duration>,
+    pub(crate) warn_after: Option<Duration>,
+    #[cfg(feature = "admin-http")]
+    pub(crate) admin_http: Option<AdminHttp>,
+    #[cfg(all(feature = "control-socket", unix))]
+    pub(crate) control_socket: Option<std::path::PathBuf>,
 }
 
 impl Default for SchedulerBuilder {
@@ -22,43 +55,115 @@ impl Default for SchedulerBuilder {
 impl SchedulerBuilder {
     /// Create a new scheduler builder with default config (empty)
     pub fn new() -> Self {
-        Self {
-            config: Arc::new(Config::default()),
-            runnable_tasks: Vec::new(),
-            registered_instances: Vec::new(),
-        }
+        Self::with_config(Config::default())
     }
 
     /// Create with TOML config file
     /// 
+    /// If profiles are active (via `CONCERTO_PROFILES_ACTIVE` or `concerto.profiles.active`
+    /// in the file), profile files like `application-prod.toml` are layered on top.
+    /// 
     /// # Panics
     /// 
     /// Panics if the config file cannot be loaded or parsed.
     /// This is intentional as configuration errors should be caught early during setup.
+    /// Use `try_with_toml()` to handle this as an error.
     pub fn with_toml(path: &str) -> Self {
-        let config = load_toml_config(path)
-            .unwrap_or_else(|e| panic!("Failed to load TOML config from '{}': {}", path, e));
-        Self {
-            config: Arc::new(config),
-            runnable_tasks: Vec::new(),
-            registered_instances: Vec::new(),
-        }
+        Self::try_with_toml(path)
+            .unwrap_or_else(|e| panic!("Failed to load TOML config from '{}': {}", path, e))
+    }
+
+    /// Create with TOML config file, returning an error instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::ConfigLoad`] if the config file cannot be loaded or parsed.
+    pub fn try_with_toml(path: &str) -> Result<Self, ConcertoError> {
+        Self::try_with_loader(ConfigLoader::new().file_with_format(path, ConfigFormat::Toml))
     }
 
     /// Create with YAML config file
     /// 
+    /// If profiles are active (via `CONCERTO_PROFILES_ACTIVE` or `concerto.profiles.active`
+    /// in the file), profile files like `application-prod.yaml` are layered on top.
+    /// 
     /// # Panics
     /// 
     /// Panics if the config file cannot be loaded or parsed.
     /// This is intentional as configuration errors should be caught early during setup.
+    /// Use `try_with_yaml()` to handle this as an error.
     pub fn with_yaml(path: &str) -> Self {
-        let config = load_yaml_config(path)
-            .unwrap_or_else(|e| panic!("Failed to load YAML config from '{}': {}", path, e));
-        Self {
-            config: Arc::new(config),
-            runnable_tasks: Vec::new(),
-            registered_instances: Vec::new(),
+        Self::try_with_yaml(path)
+            .unwrap_or_else(|e| panic!("Failed to load YAML config from '{}': {}", path, e))
+    }
+
+    /// Create with YAML config file, returning an error instead of panicking
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use concerto_runtime::{ConcertoError, SchedulerBuilder};
+    ///
+    /// let builder = match SchedulerBuilder::try_with_yaml("config/application.yaml") {
+    ///     Ok(builder) => builder,
+    ///     Err(ConcertoError::ConfigLoad(reason)) => {
+    ///         eprintln!("Config unavailable ({}), using defaults", reason);
+    ///         SchedulerBuilder::new()
+    ///     }
+    ///     Err(e) => panic!("{}", e),
+    /// };
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::ConfigLoad`] if the config file cannot be loaded or parsed.
+    pub fn try_with_yaml(path: &str) -> Result<Self, ConcertoError> {
+        Self::try_with_loader(ConfigLoader::new().file_with_format(path, ConfigFormat::Yaml))
+    }
+
+    /// Create with layered config sources
+    ///
+    /// Use this to combine several files, a `.env` file, a custom environment
+    /// variable prefix or command-line overrides. See [`ConfigLoader`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use concerto_runtime::{ConfigLoader, SchedulerBuilder};
+    ///
+    /// let scheduler = SchedulerBuilder::with_loader(
+    ///     ConfigLoader::new()
+    ///         .file("config/application.properties")
+    ///         .dotenv(".env")
+    ///         .env_prefix("BILLING")
+    ///         .args(std::env::args()),
+    /// )
+    /// .build();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a config source cannot be loaded or parsed.
+    /// Use `try_with_loader()` to handle this as an error.
+    pub fn with_loader(loader: ConfigLoader) -> Self {
+        Self::try_with_loader(loader).unwrap_or_else(|e| panic!("Failed to load config: {}", e))
+    }
+
+    /// Create with layered config sources, returning an error instead of panicking
+    ///
+    /// The loader is kept to re-layer profile files and to reload the config later.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::ConfigLoad`] if a config source cannot be loaded or parsed.
+    pub fn try_with_loader(loader: ConfigLoader) -> Result<Self, ConcertoError> {
+        let (config, profiles) = loader.load_with_profiles()?;
+        let mut builder = Self::with_config(config);
+        builder.config_loader = Some(loader);
+        if !profiles.is_empty() {
+            builder.active_profiles = Some(profiles);
         }
+        Ok(builder)
     }
 
     /// Create with custom config
@@ -67,9 +172,238 @@ impl SchedulerBuilder {
             config: Arc::new(config),
             runnable_tasks: Vec::new(),
             registered_instances: Vec::new(),
+            include_tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            config_loader: None,
+            active_profiles: None,
+            relayer_profiles: false,
+            watch_config: None,
+            reload_on_sighup: false,
+            fail_fast: false,
+            history_size: DEFAULT_HISTORY_SIZE,
+            listeners: Vec::new(),
+            span_layout: SpanLayout::Default,
+            lateness_warning: None,
+            warn_after: None,
+            #[cfg(feature = "admin-http")]
+            admin_http: None,
+            #[cfg(all(feature = "control-socket", unix))]
+            control_socket: None,
         }
     }
 
+    /// Set the active profiles explicitly
+    ///
+    /// Overrides `CONCERTO_PROFILES_ACTIVE` and `concerto.profiles.active`.
+    /// Tasks declared with `profiles = [...]` only run when one of their
+    /// profiles is active. When the builder was created with `with_toml`/`with_yaml`/`with_loader`,
+    /// the matching profile files (e.g. `application-prod.yaml`) are layered
+    /// over the base file when the scheduler is built; a profile file that cannot
+    /// be parsed makes [`try_build`](Self::try_build) fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concerto_runtime::SchedulerBuilder;
+    ///
+    /// let scheduler = SchedulerBuilder::new()
+    ///     .active_profiles(["prod"])
+    ///     .build();
+    /// ```
+    pub fn active_profiles<I, S>(mut self, profiles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.active_profiles = Some(profiles.into_iter().map(Into::into).collect());
+        self.relayer_profiles = self.config_loader.is_some();
+        self
+    }
+
+    /// Reload the config whenever one of its files changes
+    ///
+    /// Config files (base, profile and `.env` files) are polled every `poll_interval`.
+    /// On change, tasks are rescheduled, enabled or disabled as described in
+    /// [`SchedulerHandle::reload_config`](crate::SchedulerHandle::reload_config).
+    /// Only has an effect when the builder was created with `with_toml`/`with_yaml`/`with_loader`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use concerto_runtime::SchedulerBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let scheduler = SchedulerBuilder::with_yaml("config/application.yaml")
+    ///     .watch_config(Duration::from_secs(2))
+    ///     .build();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poll_interval` is zero.
+    pub fn watch_config(mut self, poll_interval: Duration) -> Self {
+        assert!(!poll_interval.is_zero(), "watch_config poll interval must be greater than zero");
+        self.watch_config = Some(poll_interval);
+        self
+    }
+
+    /// Reload the config when the process receives `SIGHUP` (Unix only)
+    pub fn reload_on_sighup(mut self) -> Self {
+        self.reload_on_sighup = true;
+        self
+    }
+
+    /// Abort `start()` if any task fails to register
+    ///
+    /// By default failing tasks (e.g. a missing placeholder or an invalid cron
+    /// expression) are logged and skipped while the others run. With fail-fast,
+    /// every task is validated before any of them is started and `start()`
+    /// returns [`ConcertoError::StartFailed`] with the full report instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use concerto_runtime::{ConcertoError, SchedulerBuilder};
+    ///
+    /// # async fn run() {
+    /// let scheduler = SchedulerBuilder::with_yaml("config/application.yaml")
+    ///     .fail_fast(true)
+    ///     .build();
+    ///
+    /// match scheduler.start().await {
+    ///     Ok(handle) => println!("{}", handle.start_report()),
+    ///     Err(ConcertoError::StartFailed(report)) => {
+    ///         eprintln!("{}", report);
+    ///         std::process::exit(1);
+    ///     }
+    ///     Err(e) => panic!("{}", e),
+    /// }
+    /// # }
+    /// ```
+    pub fn fail_fast(mut self, enabled: bool) -> Self {
+        self.fail_fast = enabled;
+        self
+    }
+
+    /// Number of past executions kept per task for `SchedulerHandle::history` (default 10)
+    ///
+    /// Entries are about 80 bytes plus the error message, and a task's buffer is
+    /// only allocated once it first fires. Use `0` to keep no history.
+    pub fn history_size(mut self, entries: usize) -> Self {
+        self.history_size = entries;
+        self
+    }
+
+    /// Register a listener called on every task's lifecycle events
+    ///
+    /// Listeners are called in registration order. See [`TaskListener`].
+    pub fn listener(mut self, listener: impl TaskListener) -> Self {
+        self.listeners.push(Arc::new(listener));
+        self
+    }
+
+    /// Field layout of the `concerto.task` span wrapped around every run
+    ///
+    /// Use [`SpanLayout::OpenTelemetry`] when exporting through `tracing-opentelemetry`.
+    pub fn span_layout(mut self, layout: SpanLayout) -> Self {
+        self.span_layout = layout;
+        self
+    }
+
+    /// Log a warning when a run starts more than `threshold` after its fire time
+    ///
+    /// Lateness is measured for every run, cron and interval alike, and is
+    /// available in task info, history and metrics either way. Runs starting
+    /// late across many tasks usually mean the runtime is overloaded.
+    pub fn warn_on_lateness(mut self, threshold: Duration) -> Self {
+        self.lateness_warning = Some(threshold);
+        self
+    }
+
+    /// Report runs in progress for longer than `limit` as stuck
+    ///
+    /// Applies to tasks without their own `warn_after` attribute. A watchdog checks
+    /// running tasks every second; a stuck run is logged as a warning, sent as
+    /// `SchedulerEvent::TaskStuck` and shown as `TaskState::Stuck` until it finishes.
+    /// The run itself is left alone.
+    pub fn warn_after(mut self, limit: Duration) -> Self {
+        self.warn_after = Some(limit);
+        self
+    }
+
+    /// Serve a local HTTP API to inspect and control the scheduler once started
+    ///
+    /// See [`AdminHttp`] for the endpoints. The address is bound by `start`,
+    /// which fails with [`ConcertoError::Listen`] if it is unavailable.
+    /// Requires the `admin-http` feature.
+    #[cfg(feature = "admin-http")]
+    pub fn admin_http(mut self, admin: AdminHttp) -> Self {
+        self.admin_http = Some(admin);
+        self
+    }
+
+    /// Accept control commands on a Unix socket once started, e.g. from `concertoctl`
+    ///
+    /// Each line sent is a JSON request such as `{"command": "pause", "task": "cleanup"}`,
+    /// answered by one line `{"ok": true, "result": ...}` or `{"ok": false, "error": "..."}`.
+    /// Commands are `list`, `status` (health, or a task and its last runs with `task`),
+    /// `trigger`, `pause`, `resume`, `reload-config` and `drain` (see
+    /// [`SchedulerHandle::drain`](crate::SchedulerHandle::drain); optional `timeout_ms`,
+    /// default 30 seconds).
+    ///
+    /// The socket is created by `start` with owner-only permissions and removed on
+    /// shutdown. Requires the `control-socket` feature, on Unix.
+    #[cfg(all(feature = "control-socket", unix))]
+    pub fn control_socket(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.control_socket = Some(path.into());
+        self
+    }
+
+    /// Only keep tasks labelled with at least one of the given tags
+    ///
+    /// Can be called multiple times; tags accumulate. Tasks without any of
+    /// these tags are dropped in `build()` and never reach the scheduler.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concerto_runtime::SchedulerBuilder;
+    ///
+    /// // Worker pods only run tasks declared with `tags = ["worker"]`
+    /// let scheduler = SchedulerBuilder::new()
+    ///     .include_tags(["worker"])
+    ///     .build();
+    /// ```
+    pub fn include_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.include_tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop tasks labelled with any of the given tags
+    ///
+    /// Exclusion takes precedence over `include_tags`.
+    pub fn exclude_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.exclude_tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Check whether a task with the given tags passes the tag filters
+    fn is_selected(&self, tags: &[&str]) -> bool {
+        if tags.iter().any(|tag| self.exclude_tags.iter().any(|t| t == tag)) {
+            return false;
+        }
+        self.include_tags.is_empty()
+            || tags.iter().any(|tag| self.include_tags.iter().any(|t| t == tag))
+    }
+
     /// Register an instance with #[scheduled] methods
     ///
     /// This unified method can handle both:
@@ -82,7 +416,7 @@ impl SchedulerBuilder {
     /// # Example with #[scheduled] methods
     ///
     /// ```rust
-    /// use scheduled_runtime::SchedulerBuilder;
+    /// use concerto_runtime::SchedulerBuilder;
     /// 
     /// struct UserService {
     ///     name: String,
@@ -107,7 +441,7 @@ impl SchedulerBuilder {
     /// # Example with Runnable trait
     ///
     /// ```rust
-    /// use scheduled_runtime::{SchedulerBuilder, Runnable};
+    /// use concerto_runtime::{SchedulerBuilder, Runnable};
     /// 
     /// struct MyTask {
     ///     name: String,
@@ -132,7 +466,51 @@ impl SchedulerBuilder {
     ///     .build();
     /// # }
     /// ```
-    pub fn register<T>(mut self, instance: T) -> Self
+    #[track_caller]
+    pub fn register<T>(self, instance: T) -> Self
+    where
+        T: ScheduledInstance + 'static,
+    {
+        self.register_instance(instance, None)
+    }
+
+    /// Register an instance under a distinct name and config prefix
+    ///
+    /// Use this to run several instances of the same type side by side.
+    /// Task names become `{name}::{method}` instead of `{type_name}::{method}`,
+    /// and relative placeholders like `${.interval}` resolve to `${name.interval}`.
+    ///
+    /// # Example
+    ///
+    /// ```yaml
+    /// tenant_a:
+    ///   interval: 10s
+    /// tenant_b:
+    ///   interval: 1m
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// #[scheduled]
+    /// impl TenantSync {
+    ///     #[scheduled(fixed_rate = "${.interval:30s}")]
+    ///     async fn sync(&self) { /* ... */ }
+    /// }
+    ///
+    /// let scheduler = SchedulerBuilder::with_yaml("config/application.yaml")
+    ///     .register_named(TenantSync::new("a"), "tenant_a")  // task "tenant_a::sync"
+    ///     .register_named(TenantSync::new("b"), "tenant_b")  // task "tenant_b::sync"
+    ///     .build();
+    /// ```
+    #[track_caller]
+    pub fn register_named<T>(self, instance: T, name: &str) -> Self
+    where
+        T: ScheduledInstance + 'static,
+    {
+        self.register_instance(instance, Some(name.to_string()))
+    }
+
+    #[track_caller]
+    fn register_instance<T>(mut self, instance: T, instance_name: Option<String>) -> Self
     where
         T: ScheduledInstance + 'static,
     {
@@ -146,12 +524,14 @@ impl SchedulerBuilder {
             let inst_clone = instance_arc_clone.clone();
             Box::pin(async move {
                 let future = inst_clone.call_scheduled_method(&method_name);
-                future.await;
-            }) as std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'static>>
+                future.await
+            }) as TaskFuture
         });
 
         self.registered_instances.push(RegisteredInstance {
             type_name,
+            instance_name,
+            registered_at: std::panic::Location::caller(),
             instance: instance_arc as Arc<dyn std::any::Any + Send + Sync>,
             methods,
             caller,
@@ -165,46 +545,234 @@ impl SchedulerBuilder {
     /// This will:
     /// - Collect all tasks registered via `.register()`
     /// - Auto-discover all tasks marked with `#[scheduled]` macro
+    /// - Check that every task name is unique
     /// - Return a `Scheduler` ready to start
     ///
     /// # Example
     ///
     /// ```rust
-    /// use scheduled_runtime::SchedulerBuilder;
+    /// use concerto_runtime::SchedulerBuilder;
     /// 
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let scheduler = SchedulerBuilder::new()
     ///     // In actual code, add .with_toml("config.toml")
     ///     // In actual code, add .register(MyTask)
-    ///     .build();  // <- No error here, just setup
+    ///     .build();  // <- Only fails on duplicate task names
     /// 
     /// // scheduler.start().await?;  // <- Errors happen here
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if two tasks share the same name, or if the files of the profiles set
+    /// with `active_profiles` cannot be loaded. Use `try_build()` to handle this as an error.
     pub fn build(self) -> Scheduler {
+        self.try_build().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Build the scheduler, returning an error instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::ConfigLoad`] if the files of the profiles set with
+    /// `active_profiles` cannot be loaded, and [`ConcertoError::DuplicateTaskNames`],
+    /// listing the source locations of all clashing tasks, if two tasks share a name.
+    pub fn try_build(mut self) -> Result<Scheduler, ConcertoError> {
         use crate::registry::SCHEDULED_TASKS;
         use crate::task::ScheduledTask;
+
+        if self.relayer_profiles {
+            if let (Some(loader), Some(profiles)) = (self.config_loader.take(), &self.active_profiles) {
+                let loader = loader.profiles(profiles.clone());
+                self.config = Arc::new(loader.load()?);
+                self.config_loader = Some(loader);
+            }
+        }
         
         // Collect scheduled tasks from registry (auto-discovered #[scheduled] functions)
-        let scheduled_tasks: Vec<ScheduledTask> = SCHEDULED_TASKS.iter().map(|f| f()).collect();
+        let mut scheduled_tasks: Vec<ScheduledTask> = SCHEDULED_TASKS.iter().map(|f| f()).collect();
+        let mut runnable_tasks = std::mem::take(&mut self.runnable_tasks);
+        let mut registered_instances = std::mem::take(&mut self.registered_instances);
+
+        // Apply tag filters to every task source
+        let mut filtered_count = 0;
+        let mut is_selected = |task_name: &str, tags: &[&str]| {
+            let selected = self.is_selected(tags);
+            if !selected {
+                debug!(
+                    task_name = %task_name,
+                    tags = ?tags,
+                    "Task excluded by tag filters"
+                );
+                filtered_count += 1;
+            }
+            selected
+        };
+        scheduled_tasks.retain(|task| is_selected(task.name, task.tags));
+        runnable_tasks.retain(|task| is_selected(task.name, task.tags));
+        for registered_instance in &mut registered_instances {
+            let mut methods = std::mem::take(&mut registered_instance.methods);
+            methods.retain(|method| is_selected(&registered_instance.task_name(method), method.tags));
+            registered_instance.methods = methods;
+        }
 
-        let method_task_count: usize = self.registered_instances.iter()
+        let active_profiles = self
+            .active_profiles
+            .take()
+            .unwrap_or_else(|| active_profiles_from(&self.config));
+
+        let method_task_count: usize = registered_instances.iter()
             .map(|inst| inst.methods.len())
             .sum();
 
         info!(
-            runnable_tasks = self.runnable_tasks.len(),
+            runnable_tasks = runnable_tasks.len(),
             scheduled_tasks = scheduled_tasks.len(),
             method_tasks = method_task_count,
+            filtered_by_tags = filtered_count,
+            active_profiles = ?active_profiles,
             "Building scheduler"
         );
 
-        Scheduler {
+        // Collect metadata of every task that made it through the filters
+        let mut task_metadata: Vec<TaskMetadata> = Vec::new();
+        task_metadata.extend(runnable_tasks.iter().map(|task| TaskMetadata {
+            name: task.name.to_string(),
+            schedule_type: task.schedule_type,
+            schedule_value: task.schedule_value,
+            tags: task.tags,
+            profiles: task.profiles,
+            description: task.description,
+            owner: task.owner,
+            source: TaskSource {
+                file: task.file,
+                line: task.line,
+                registered_at: None,
+            },
+        }));
+        task_metadata.extend(scheduled_tasks.iter().map(|task| TaskMetadata {
+            name: task.name.to_string(),
+            schedule_type: task.schedule_type,
+            schedule_value: task.schedule_value,
+            tags: task.tags,
+            profiles: task.profiles,
+            description: task.description,
+            owner: task.owner,
+            source: TaskSource {
+                file: task.file,
+                line: task.line,
+                registered_at: None,
+            },
+        }));
+        for registered_instance in &registered_instances {
+            task_metadata.extend(registered_instance.methods.iter().map(|method| TaskMetadata {
+                name: registered_instance.task_name(method),
+                schedule_type: method.schedule_type,
+                schedule_value: method.schedule_value,
+                tags: method.tags,
+                profiles: method.profiles,
+                description: method.description,
+                owner: method.owner,
+                source: TaskSource {
+                    file: method.file,
+                    line: method.line,
+                    registered_at: Some(registered_instance.registered_at),
+                },
+            }));
+        }
+
+        check_unique_names(&task_metadata)?;
+
+        Ok(Scheduler {
             config: self.config,
-            runnable_tasks: self.runnable_tasks,
+            runnable_tasks,
             scheduled_tasks,
-            registered_instances: self.registered_instances,
+            registered_instances,
+            task_metadata,
+            active_profiles,
+            config_loader: self.config_loader,
+            watch_config: self.watch_config,
+            reload_on_sighup: self.reload_on_sighup,
+            fail_fast: self.fail_fast,
+            history_size: self.history_size,
+            listeners: self.listeners,
+            events: tokio::sync::broadcast::channel(EVENT_CAPACITY).0,
+            span_layout: self.span_layout,
+            lateness_warning: self.lateness_warning,
+            warn_after: self.warn_after,
+            #[cfg(feature = "admin-http")]
+            admin_http: self.admin_http,
+            #[cfg(all(feature = "control-socket", unix))]
+            control_socket: self.control_socket,
+        })
+    }
+}
+
+/// Report tasks sharing the same name, with the source locations of every clash
+fn check_unique_names(task_metadata: &[TaskMetadata]) -> Result<(), ConcertoError> {
+    let mut by_name: BTreeMap<&str, Vec<&TaskMetadata>> = BTreeMap::new();
+    for task in task_metadata {
+        by_name.entry(task.name.as_str()).or_default().push(task);
+    }
+
+    let duplicates: Vec<(String, Vec<TaskSource>)> = by_name
+        .into_iter()
+        .filter(|(_, tasks)| tasks.len() > 1)
+        .map(|(name, tasks)| (name.to_string(), tasks.iter().map(|task| task.source).collect()))
+        .collect();
+
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(ConcertoError::DuplicateTaskNames(duplicates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// `application.toml` plus profile files in a fresh directory
+    fn config_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("concerto-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("application.toml"), "[app]\ninterval = \"10s\"\nname = \"base\"\n").unwrap();
+        for (file, content) in files {
+            std::fs::write(dir.join(file), content).unwrap();
         }
+        dir
+    }
+
+    fn loader(dir: &std::path::Path) -> ConfigLoader {
+        ConfigLoader::new().file(dir.join("application.toml"))
+    }
+
+    #[test]
+    fn active_profiles_layer_profile_files_when_built() {
+        let dir = config_dir("profile-overlay", &[("application-prod.toml", "[app]\ninterval = \"20s\"\n")]);
+        let scheduler = SchedulerBuilder::with_loader(loader(&dir))
+            .active_profiles(["prod"])
+            .try_build()
+            .unwrap();
+        assert_eq!(scheduler.config.get_string("app.interval").unwrap(), "20s");
+        assert_eq!(scheduler.config.get_string("app.name").unwrap(), "base");
+        assert_eq!(scheduler.active_profiles, ["prod"]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn broken_profile_file_fails_try_build() {
+        let dir = config_dir("profile-broken", &[("application-prod.toml", "[app\ninterval = ")]);
+        // Setting the profile does not load anything yet
+        let builder = SchedulerBuilder::with_loader(loader(&dir)).active_profiles(["prod"]);
+        assert!(matches!(builder.try_build(), Err(ConcertoError::ConfigLoad(_))));
+
+        // Without the profile the base file alone is fine
+        let scheduler = SchedulerBuilder::with_loader(loader(&dir)).try_build().unwrap();
+        assert_eq!(scheduler.config.get_string("app.interval").unwrap(), "10s");
+        std::fs::remove_dir_all(dir).unwrap();
     }
 }
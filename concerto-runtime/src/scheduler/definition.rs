@@ -0,0 +1,481 @@
+use super::info::{TaskKind, TaskOutcome};
+use super::instance::{MethodCaller, RegisteredInstance};
+use crate::condition::{evaluate_enabled, TaskCondition};
+use crate::config::{resolve_config_value, scope_placeholders, trace_placeholders, ResolvedValue};
+use crate::error::ConcertoError;
+use crate::runnable::{Runnable, RunnableTask};
+use crate::schedule::Schedule;
+use crate::task::{ScheduledMethodMetadata, ScheduledTask, TaskFuture};
+use crate::time_unit::TimeUnit;
+use config::Config;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, warn, Instrument, Span};
+
+/// What runs when a task fires
+#[derive(Clone)]
+pub(crate) enum TaskAction {
+    /// `Runnable::run`, executed on the blocking thread pool
+    Runnable(Arc<dyn Runnable>),
+    /// Handler generated for a `#[scheduled]` function
+    Function(fn() -> TaskFuture),
+    /// `#[scheduled]` method of a registered instance
+    Method {
+        instance: Arc<dyn std::any::Any + Send + Sync>,
+        caller: MethodCaller,
+        method_name: &'static str,
+    },
+}
+
+impl TaskAction {
+    pub(crate) fn kind(&self) -> TaskKind {
+        match self {
+            TaskAction::Runnable(_) => TaskKind::Runnable,
+            TaskAction::Function(_) => TaskKind::Function,
+            TaskAction::Method { .. } => TaskKind::Method,
+        }
+    }
+
+    /// Run the task once, catching errors and panics
+    ///
+    /// A run exceeding `timeout` is aborted. Blocking `Runnable` tasks cannot be
+    /// interrupted: they are reported as timed out and finish in the background.
+    pub(crate) async fn run(&self, timeout: Option<Duration>) -> TaskOutcome {
+        let handle = match self {
+            TaskAction::Runnable(instance) => {
+                let instance = instance.clone();
+                let span = Span::current();
+                tokio::task::spawn_blocking(move || {
+                    span.in_scope(|| instance.run());
+                    Ok(())
+                })
+            }
+            TaskAction::Function(handler) => tokio::spawn(handler().instrument(Span::current())),
+            TaskAction::Method { instance, caller, method_name } => {
+                tokio::spawn(caller(instance.as_ref(), method_name).instrument(Span::current()))
+            }
+        };
+        let result = match timeout {
+            Some(limit) => {
+                let abort = handle.abort_handle();
+                match tokio::time::timeout(limit, handle).await {
+                    Ok(result) => result,
+                    Err(_) => {
+                        abort.abort();
+                        return TaskOutcome::Timeout(limit);
+                    }
+                }
+            }
+            None => handle.await,
+        };
+        match result {
+            Ok(Ok(())) => TaskOutcome::Success,
+            Ok(Err(message)) => TaskOutcome::Failed(message),
+            Err(e) if e.is_panic() => TaskOutcome::Panicked(panic_message(e.into_panic())),
+            Err(_) => TaskOutcome::Failed("task was cancelled".to_string()),
+        }
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "non-string panic payload".to_string(),
+        },
+    }
+}
+
+/// A task as declared, with schedule values still unresolved
+///
+/// Definitions are kept for the lifetime of the scheduler so that placeholders
+/// can be resolved again when the config is reloaded.
+#[derive(Clone)]
+pub(crate) struct TaskDefinition {
+    pub(crate) name: String,
+    /// `Runnable`, `Scheduled` or `Method`, used in logs
+    pub(crate) task_type: &'static str,
+    pub(crate) schedule_type: &'static str,
+    pub(crate) schedule_value: &'static str,
+    pub(crate) initial_delay: &'static str,
+    pub(crate) warn_after: &'static str,
+    pub(crate) expect_success_within: &'static str,
+    pub(crate) timeout: &'static str,
+    pub(crate) enabled: &'static str,
+    pub(crate) condition: Option<TaskCondition>,
+    pub(crate) time_unit: &'static str,
+    pub(crate) zone: &'static str,
+    pub(crate) profiles: &'static [&'static str],
+    /// Prefix for relative placeholders (`${.key}`)
+    pub(crate) config_prefix: Option<String>,
+    pub(crate) action: TaskAction,
+}
+
+/// When a task fires, after placeholders were resolved
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Trigger {
+    Cron { expression: String, zone: String },
+    FixedRate { interval_millis: u64 },
+    FixedDelay { interval_millis: u64 },
+}
+
+impl std::fmt::Display for Trigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Trigger::Cron { expression, zone } => write!(f, "cron '{}' ({})", expression, zone),
+            Trigger::FixedRate { interval_millis } => write!(f, "fixed_rate {}ms", interval_millis),
+            Trigger::FixedDelay { interval_millis } => write!(f, "fixed_delay {}ms", interval_millis),
+        }
+    }
+}
+
+impl Trigger {
+    /// Trigger running a task on `schedule`; `once` schedules are not supported
+    pub(crate) fn from_schedule(schedule: &Schedule) -> Result<Self, ConcertoError> {
+        if let Some((expression, zone)) = schedule.cron_parts() {
+            return Ok(Trigger::Cron { expression: expression.to_string(), zone: zone.to_string() });
+        }
+        let interval = schedule
+            .interval()
+            .ok_or_else(|| ConcertoError::UnsupportedSchedule(schedule.to_string()))?;
+        let interval_millis = interval.as_millis() as u64;
+        if interval_millis == 0 {
+            return Err(ConcertoError::InvalidInterval { value: schedule.to_string() });
+        }
+        Ok(if schedule.is_fixed_delay() {
+            Trigger::FixedDelay { interval_millis }
+        } else {
+            Trigger::FixedRate { interval_millis }
+        })
+    }
+
+    /// Previewable schedule for this trigger
+    pub(crate) fn schedule(&self) -> Result<Schedule, ConcertoError> {
+        match self {
+            Trigger::Cron { expression, zone } => Schedule::cron(expression, zone),
+            Trigger::FixedRate { interval_millis } => {
+                Ok(Schedule::fixed_rate(std::time::Duration::from_millis(*interval_millis)))
+            }
+            Trigger::FixedDelay { interval_millis } => {
+                Ok(Schedule::fixed_delay(std::time::Duration::from_millis(*interval_millis)))
+            }
+        }
+    }
+}
+
+/// Fully resolved schedule of a task
+///
+/// Two schedules compare equal when the task does not need to be rescheduled.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResolvedSchedule {
+    pub(crate) trigger: Trigger,
+    pub(crate) initial_delay_millis: u64,
+    /// Running time after which a run is reported as stuck
+    pub(crate) warn_after: Option<Duration>,
+    /// Running time after which a run is cancelled
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl ResolvedSchedule {
+    /// Whether both schedules fire at the same times, so the job can be kept
+    pub(crate) fn same_job(&self, other: &ResolvedSchedule) -> bool {
+        self.trigger == other.trigger && self.initial_delay_millis == other.initial_delay_millis
+    }
+}
+
+impl TaskDefinition {
+    pub(crate) fn from_runnable(task: RunnableTask) -> Self {
+        Self {
+            name: task.name.to_string(),
+            task_type: "Runnable",
+            schedule_type: task.schedule_type,
+            schedule_value: task.schedule_value,
+            initial_delay: task.initial_delay,
+            warn_after: task.warn_after,
+            expect_success_within: task.expect_success_within,
+            timeout: task.timeout,
+            enabled: task.enabled,
+            condition: task.condition,
+            time_unit: task.time_unit,
+            zone: task.zone,
+            profiles: task.profiles,
+            config_prefix: None,
+            action: TaskAction::Runnable(task.instance),
+        }
+    }
+
+    pub(crate) fn from_scheduled(task: ScheduledTask) -> Self {
+        Self {
+            name: task.name.to_string(),
+            task_type: "Scheduled",
+            schedule_type: task.schedule_type,
+            schedule_value: task.schedule_value,
+            initial_delay: task.initial_delay,
+            warn_after: task.warn_after,
+            expect_success_within: task.expect_success_within,
+            timeout: task.timeout,
+            enabled: task.enabled,
+            condition: task.condition,
+            time_unit: task.time_unit,
+            zone: task.zone,
+            profiles: task.profiles,
+            config_prefix: None,
+            action: TaskAction::Function(task.handler),
+        }
+    }
+
+    pub(crate) fn from_method(instance: &RegisteredInstance, method: &ScheduledMethodMetadata) -> Self {
+        Self {
+            name: instance.task_name(method),
+            task_type: "Method",
+            schedule_type: method.schedule_type,
+            schedule_value: method.schedule_value,
+            initial_delay: method.initial_delay,
+            warn_after: method.warn_after,
+            expect_success_within: method.expect_success_within,
+            timeout: method.timeout,
+            enabled: method.enabled,
+            condition: method.condition,
+            time_unit: method.time_unit,
+            zone: method.zone,
+            profiles: method.profiles,
+            config_prefix: instance.config_prefix().map(str::to_string),
+            action: TaskAction::Method {
+                instance: instance.instance.clone(),
+                caller: instance.caller.clone(),
+                method_name: method.method_name,
+            },
+        }
+    }
+
+    /// Resolve a value, expanding relative placeholders under the config prefix
+    fn resolve(&self, value: &str, config: &Config) -> Result<String, ConcertoError> {
+        resolve_config_value(&scope_placeholders(value, self.config_prefix.as_deref()), config)
+    }
+
+    /// Placeholders used by the task, with their values and sources
+    pub(crate) fn resolved_values(&self, config: &Config) -> Vec<ResolvedValue> {
+        [
+            self.enabled,
+            self.schedule_value,
+            self.initial_delay,
+            self.warn_after,
+            self.expect_success_within,
+            self.timeout,
+            self.time_unit,
+            self.zone,
+        ]
+        .iter()
+            .flat_map(|value| {
+                trace_placeholders(&scope_placeholders(value, self.config_prefix.as_deref()), config)
+            })
+            .collect()
+    }
+
+    /// Evaluate the `enabled` expression and optional condition
+    ///
+    /// Returns the reason the task is disabled, or `None` if it should be scheduled.
+    pub(crate) fn disabled_reason(&self, config: &Config) -> Result<Option<&'static str>, ConcertoError> {
+        let enabled = scope_placeholders(self.enabled, self.config_prefix.as_deref());
+        if !evaluate_enabled(&enabled, config)? {
+            return Ok(Some("enabled evaluated to false"));
+        }
+        if let Some(condition) = self.condition {
+            if !condition(config) {
+                return Ok(Some("condition not met"));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Resolve `expect_success_within` against the config
+    ///
+    /// Separate from the schedule because it also applies while the task is disabled.
+    pub(crate) fn resolve_expect_success_within(&self, config: &Config) -> Result<Option<Duration>, ConcertoError> {
+        if self.expect_success_within.is_empty() {
+            return Ok(None);
+        }
+        let time_unit = parse_time_unit(&self.resolve(self.time_unit, config)?);
+        parse_limit(&self.resolve(self.expect_success_within, config)?, time_unit)
+    }
+
+    /// Resolve schedule value, time unit, initial delay, warn_after, timeout and zone against the config
+    pub(crate) fn resolve_schedule(&self, config: &Config) -> Result<ResolvedSchedule, ConcertoError> {
+        let time_unit_str = self.resolve(self.time_unit, config)?;
+        let time_unit = parse_time_unit(&time_unit_str);
+
+        let initial_delay = self.resolve(self.initial_delay, config)?;
+        let initial_delay_millis = parse_initial_delay(&initial_delay, time_unit);
+
+        let warn_after = self.resolve(self.warn_after, config)?;
+        let warn_after = parse_limit(&warn_after, time_unit)?;
+        let timeout = self.resolve(self.timeout, config)?;
+        let timeout = parse_limit(&timeout, time_unit)?;
+
+        let zone_str = self.resolve(self.zone, config)?;
+
+        let trigger = match self.schedule_type {
+            "cron" => {
+                let expression = self.resolve(self.schedule_value, config)?;
+                let zone = parse_zone_display(&zone_str);
+                print_cron_info(&expression, &zone, initial_delay_millis, &time_unit_str);
+                Trigger::Cron { expression, zone }
+            }
+            "fixed_rate" | "fixed_delay" => {
+                let interval_str = self.resolve(self.schedule_value, config)?;
+                let (interval_value, effective_time_unit, interval_millis) =
+                    parse_interval(&interval_str, time_unit)?;
+                if interval_millis == 0 {
+                    return Err(ConcertoError::InvalidInterval { value: interval_str });
+                }
+
+                print_interval_info(
+                    self.schedule_type,
+                    interval_value,
+                    effective_time_unit,
+                    interval_millis,
+                    initial_delay_millis,
+                    &zone_str,
+                );
+
+                if self.schedule_type == "fixed_delay" {
+                    Trigger::FixedDelay { interval_millis }
+                } else {
+                    Trigger::FixedRate { interval_millis }
+                }
+            }
+            _ => {
+                return Err(ConcertoError::UnknownScheduleType(self.schedule_type.to_string()));
+            }
+        };
+
+        Ok(ResolvedSchedule {
+            trigger,
+            initial_delay_millis,
+            warn_after,
+            timeout,
+        })
+    }
+}
+
+/// Parse and resolve time unit configuration
+fn parse_time_unit(time_unit_str: &str) -> TimeUnit {
+    time_unit_str.parse().unwrap_or_else(|_| {
+        warn!(
+            time_unit = %time_unit_str,
+            "Invalid time_unit, using milliseconds as default"
+        );
+        TimeUnit::Milliseconds
+    })
+}
+
+/// Parse and resolve initial delay in milliseconds
+fn parse_initial_delay(initial_delay: &str, time_unit: TimeUnit) -> u64 {
+    if let Some((value, parsed_unit)) = TimeUnit::parse_duration(initial_delay) {
+        parsed_unit.to_millis(value)
+    } else {
+        let initial_delay_value: u64 = initial_delay.parse().unwrap_or_else(|_| {
+            warn!(
+                initial_delay = %initial_delay,
+                "Invalid initial_delay value, using 0 as default"
+            );
+            0
+        });
+        time_unit.to_millis(initial_delay_value)
+    }
+}
+
+/// Parse warn_after, timeout or expect_success_within; empty or zero means no limit
+fn parse_limit(value: &str, time_unit: TimeUnit) -> Result<Option<Duration>, ConcertoError> {
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    let (_, _, millis) = parse_interval(value.trim(), time_unit)?;
+    Ok((millis > 0).then(|| Duration::from_millis(millis)))
+}
+
+/// Parse and resolve zone display string
+fn parse_zone_display(zone_str: &str) -> String {
+    if zone_str.to_lowercase() == "local" {
+        "Local".to_string()
+    } else {
+        zone_str.to_string()
+    }
+}
+
+/// Parse interval value and return (value, time_unit, millis)
+fn parse_interval(
+    interval_str: &str,
+    default_time_unit: TimeUnit,
+) -> Result<(u64, TimeUnit, u64), ConcertoError> {
+    let (interval_value, effective_time_unit) =
+        if let Some((value, parsed_unit)) = TimeUnit::parse_duration(interval_str) {
+            debug!(
+                interval = %interval_str,
+                value = %value,
+                time_unit = ?parsed_unit,
+                "Parsed interval shorthand"
+            );
+            (value, parsed_unit)
+        } else {
+            let value = interval_str
+                .parse::<u64>()
+                .map_err(|_| ConcertoError::InvalidInterval {
+                    value: interval_str.to_string(),
+                })?;
+            (value, default_time_unit)
+        };
+
+    let interval_millis = effective_time_unit.to_millis(interval_value);
+    Ok((interval_value, effective_time_unit, interval_millis))
+}
+
+/// Print cron task information
+fn print_cron_info(
+    cron_expr: &str,
+    zone_display: &str,
+    initial_delay_millis: u64,
+    time_unit_str: &str,
+) {
+    debug!(
+        task_type = "cron",
+        cron_expression = %cron_expr,
+        zone = %zone_display,
+        initial_delay_ms = %initial_delay_millis,
+        "Cron task configuration"
+    );
+
+    if time_unit_str.to_lowercase() != "milliseconds" {
+        warn!(
+            time_unit = %time_unit_str,
+            "time_unit parameter is ignored for cron expressions (uses absolute calendar-based time)"
+        );
+    }
+}
+
+/// Print interval task information
+fn print_interval_info(
+    schedule_type: &str,
+    interval_value: u64,
+    effective_time_unit: TimeUnit,
+    interval_millis: u64,
+    initial_delay_millis: u64,
+    zone_str: &str,
+) {
+    debug!(
+        task_type = %schedule_type,
+        interval_value = %interval_value,
+        time_unit = ?effective_time_unit,
+        interval_ms = %interval_millis,
+        initial_delay_ms = %initial_delay_millis,
+        "Interval-based task configuration"
+    );
+
+    if !zone_str.eq_ignore_ascii_case("local") && !zone_str.eq_ignore_ascii_case("utc") {
+        warn!(
+            zone = %zone_str,
+            "zone parameter is ignored for interval-based tasks (fixed_rate/fixed_delay use local system time)"
+        );
+    }
+}
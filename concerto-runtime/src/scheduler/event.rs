@@ -0,0 +1,90 @@
+use super::info::TaskExecution;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Something that happened in a scheduler, from `subscribe()`
+///
+/// `at` is when the event was emitted.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SchedulerEvent {
+    /// All tasks were scheduled and the scheduler is running
+    SchedulerStarted { at: DateTime<Utc> },
+    /// `shutdown` completed; no further events follow
+    SchedulerStopped { at: DateTime<Utc> },
+    /// The task was scheduled, at start or enabled by a config reload
+    TaskRegistered { task: String, schedule: String, at: DateTime<Utc> },
+    /// The task is not scheduled, at start or after a config reload
+    TaskDisabled { task: String, reason: String, at: DateTime<Utc> },
+    /// A config reload changed the task's schedule
+    TaskRescheduled {
+        task: String,
+        old_schedule: String,
+        new_schedule: String,
+        at: DateTime<Utc>,
+    },
+    /// A fire time was reached; followed by `TaskStarted` unless the task is paused
+    TaskFired { task: String, scheduled_at: DateTime<Utc>, at: DateTime<Utc> },
+    TaskStarted { task: String, scheduled_at: DateTime<Utc>, at: DateTime<Utc> },
+    /// A run succeeded
+    TaskCompleted { task: String, execution: TaskExecution, at: DateTime<Utc> },
+    /// A run returned an error or panicked
+    TaskFailed { task: String, execution: TaskExecution, at: DateTime<Utc> },
+    /// A run has been in progress for longer than the task's `warn_after`; sent once per run
+    TaskStuck {
+        task: String,
+        started_at: DateTime<Utc>,
+        elapsed: Duration,
+        at: DateTime<Utc>,
+    },
+    /// No run succeeded within the task's `expect_success_within`, whether it kept
+    /// failing, never fired or is disabled; sent again only after the task succeeds
+    Overdue {
+        task: String,
+        /// `None` if the task has not succeeded since the scheduler started
+        last_success: Option<DateTime<Utc>>,
+        expected_within: Duration,
+        at: DateTime<Utc>,
+    },
+    TaskPaused { task: String, at: DateTime<Utc> },
+    TaskResumed { task: String, at: DateTime<Utc> },
+}
+
+impl SchedulerEvent {
+    /// Name of the task the event is about, `None` for scheduler events
+    pub fn task(&self) -> Option<&str> {
+        match self {
+            SchedulerEvent::SchedulerStarted { .. } | SchedulerEvent::SchedulerStopped { .. } => None,
+            SchedulerEvent::TaskRegistered { task, .. }
+            | SchedulerEvent::TaskDisabled { task, .. }
+            | SchedulerEvent::TaskRescheduled { task, .. }
+            | SchedulerEvent::TaskFired { task, .. }
+            | SchedulerEvent::TaskStarted { task, .. }
+            | SchedulerEvent::TaskCompleted { task, .. }
+            | SchedulerEvent::TaskFailed { task, .. }
+            | SchedulerEvent::TaskStuck { task, .. }
+            | SchedulerEvent::Overdue { task, .. }
+            | SchedulerEvent::TaskPaused { task, .. }
+            | SchedulerEvent::TaskResumed { task, .. } => Some(task),
+        }
+    }
+
+    /// When the event was emitted
+    pub fn at(&self) -> DateTime<Utc> {
+        match self {
+            SchedulerEvent::SchedulerStarted { at }
+            | SchedulerEvent::SchedulerStopped { at }
+            | SchedulerEvent::TaskRegistered { at, .. }
+            | SchedulerEvent::TaskDisabled { at, .. }
+            | SchedulerEvent::TaskRescheduled { at, .. }
+            | SchedulerEvent::TaskFired { at, .. }
+            | SchedulerEvent::TaskStarted { at, .. }
+            | SchedulerEvent::TaskCompleted { at, .. }
+            | SchedulerEvent::TaskFailed { at, .. }
+            | SchedulerEvent::TaskStuck { at, .. }
+            | SchedulerEvent::Overdue { at, .. }
+            | SchedulerEvent::TaskPaused { at, .. }
+            | SchedulerEvent::TaskResumed { at, .. } => *at,
+        }
+    }
+}
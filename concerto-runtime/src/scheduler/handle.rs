@@ -1,23 +1,222 @@
-use tokio_cron_scheduler::JobScheduler;
+use super::event::SchedulerEvent;
+use super::health::HealthReport;
+use super::info::{TaskExecution, TaskInfo};
+use super::report::StartReport;
+use super::runtime::SchedulerRuntime;
+use crate::error::ConcertoError;
+use crate::schedule::Schedule;
+use crate::task::TaskMetadata;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
 
 /// Handle for a running scheduler
 /// Used to control and shutdown the scheduler
 pub struct SchedulerHandle {
-    pub(crate) cron_scheduler: JobScheduler,
-    pub(crate) interval_handles: Vec<tokio::task::JoinHandle<()>>,
+    pub(crate) runtime: Arc<SchedulerRuntime>,
+    pub(crate) task_metadata: Vec<TaskMetadata>,
+    pub(crate) start_report: StartReport,
+    #[cfg(feature = "admin-http")]
+    pub(crate) admin_http_addr: Option<std::net::SocketAddr>,
 }
 
 impl SchedulerHandle {
+    /// Metadata of all tasks known to the scheduler (including disabled ones)
+    pub fn tasks(&self) -> &[TaskMetadata] {
+        &self.task_metadata
+    }
+
+    /// Look up the metadata of a single task by name
+    pub fn task(&self, name: &str) -> Option<&TaskMetadata> {
+        self.task_metadata.iter().find(|task| task.name == name)
+    }
+
+    /// Registered, disabled and failed tasks at start, with reasons
+    ///
+    /// Reflects the state right after `start`; config reloads are not included.
+    pub fn start_report(&self) -> &StartReport {
+        &self.start_report
+    }
+
+    /// Address the admin HTTP server listens on, `None` without `SchedulerBuilder::admin_http`
+    ///
+    /// Useful when binding to port `0`.
+    #[cfg(feature = "admin-http")]
+    pub fn admin_http_addr(&self) -> Option<std::net::SocketAddr> {
+        self.admin_http_addr
+    }
+
+    /// Next `n` times a task will fire, `None` if there is no task with this name
+    ///
+    /// Disabled and paused tasks return an empty list. Interval tasks assume runs take no time.
+    /// To preview a schedule without a running scheduler, use [`Schedule`](crate::Schedule).
+    pub async fn next_fire_times(&self, name: &str, n: usize) -> Option<Vec<DateTime<Utc>>> {
+        self.runtime.next_fire_times(name, n).await
+    }
+
+    /// Runtime state of a task: schedule, current state, last run and counters
+    ///
+    /// Returns `None` if there is no task with this name.
+    ///
+    /// ```rust,ignore
+    /// if let Some(info) = handle.task_info("cleanup").await {
+    ///     println!("{:?}, next run at {:?}", info.state, info.next_fire);
+    ///     println!("{} ok / {} failed", info.success_count, info.failure_count);
+    /// }
+    /// ```
+    pub async fn task_info(&self, name: &str) -> Option<TaskInfo> {
+        self.runtime.task_info(name).await
+    }
+
+    /// Runtime state of every task, including disabled ones
+    pub async fn task_infos(&self) -> Vec<TaskInfo> {
+        self.runtime.task_infos().await
+    }
+
+    /// Last executions of a task, oldest first; `None` if there is no task with this name
+    ///
+    /// Keeps as many entries as `SchedulerBuilder::history_size` (default 10).
+    /// Fire times skipped while the task was paused are included.
+    ///
+    /// ```rust,ignore
+    /// for run in handle.history("sync_users").await.unwrap_or_default() {
+    ///     println!("{} took {:?}: {}", run.started_at, run.duration, run.outcome);
+    /// }
+    /// ```
+    pub async fn history(&self, name: &str) -> Option<Vec<TaskExecution>> {
+        self.runtime.history(name).await
+    }
+
+    /// Stop running a task until [`resume`](Self::resume) is called
+    ///
+    /// The task keeps its schedule; fire times are skipped while paused and a
+    /// run already in progress finishes normally. Pausing stays in effect across
+    /// config reloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::UnknownTask`] if there is no task with this name.
+    pub async fn pause(&self, name: &str) -> Result<(), ConcertoError> {
+        self.runtime.set_paused(name, true).await
+    }
+
+    /// Resume a paused task from its next fire time
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::UnknownTask`] if there is no task with this name.
+    pub async fn resume(&self, name: &str) -> Result<(), ConcertoError> {
+        self.runtime.set_paused(name, false).await
+    }
+
+    /// Run a task once right away, without waiting for its next fire time
+    ///
+    /// The run happens in the background, even while the task is paused or
+    /// disabled, and is recorded like any other run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::UnknownTask`] if there is no task with this name.
+    pub async fn trigger(&self, name: &str) -> Result<(), ConcertoError> {
+        self.runtime.trigger(name).await
+    }
+
+    /// Move a scheduled task to another schedule
+    ///
+    /// Takes effect right away, without initial delay. The next config reload
+    /// restores the schedule from the config.
+    ///
+    /// ```rust,ignore
+    /// handle.reschedule("cleanup", &Schedule::cron("0 30 3 * * *", "UTC")?).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::UnknownTask`] or [`ConcertoError::TaskNotScheduled`]
+    /// if the task is missing or disabled, [`ConcertoError::UnsupportedSchedule`] for
+    /// a `once` schedule, or an engine error if the new job cannot be added (the
+    /// task is then left unscheduled).
+    pub async fn reschedule(&self, name: &str, schedule: &Schedule) -> Result<(), ConcertoError> {
+        self.runtime.reschedule(name, schedule).await
+    }
+
+    /// Per-task metrics in the OpenMetrics text format, for a Prometheus scrape endpoint
+    ///
+    /// Counters of runs, failures and skipped fire times, an in-flight gauge, and
+    /// histograms of run duration and fire lateness, labelled by `task` and `kind`.
+    /// Serve it with [`OPENMETRICS_CONTENT_TYPE`](crate::OPENMETRICS_CONTENT_TYPE).
+    /// Requires the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub async fn render_metrics(&self) -> String {
+        self.runtime.render_metrics().await
+    }
+
+    /// Receive scheduler and task events as they happen
+    ///
+    /// Each receiver buffers 256 events; a receiver that falls further behind gets
+    /// `RecvError::Lagged` and skips the oldest ones. Events are only built while
+    /// someone is subscribed. To also see the events of `start`, subscribe on the
+    /// [`Scheduler`](crate::Scheduler) before starting it.
+    ///
+    /// ```rust,ignore
+    /// let mut events = handle.subscribe();
+    /// while let Ok(event) = events.recv().await {
+    ///     if let SchedulerEvent::TaskFailed { task, execution, .. } = event {
+    ///         eprintln!("{} failed: {:?}", task, execution.error());
+    ///     }
+    /// }
+    /// ```
+    pub fn subscribe(&self) -> broadcast::Receiver<SchedulerEvent> {
+        self.runtime.subscribe()
+    }
+
+    /// Check that tasks are being run and list those needing attention
+    ///
+    /// `Unhealthy` when the cron engine does not answer, a cron task missed a fire
+    /// time by more than 30 seconds, or an interval loop stopped. `Degraded` when a
+    /// scheduled task's last run failed, a run is stuck (`warn_after`) or a task is
+    /// overdue (`expect_success_within`). Suited to Kubernetes probes:
+    ///
+    /// ```rust,ignore
+    /// let health = handle.health().await;
+    /// let liveness_ok = health.is_alive();       // restart the pod when false
+    /// let readiness_ok = health.is_healthy();
+    /// println!("{}", health);                    // status and one line per problem
+    /// ```
+    pub async fn health(&self) -> HealthReport {
+        self.runtime.health().await
+    }
+
+    /// Re-read the config files and apply changes to running tasks
+    ///
+    /// Every task's placeholders are resolved again. Tasks whose schedule,
+    /// initial delay or zone changed are rescheduled, and tasks whose `enabled`
+    /// value or condition changed are started or stopped. Each change is logged.
+    ///
+    /// Profiles stay the ones the scheduler was started with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConcertoError::ReloadUnavailable`] if the scheduler was not created
+    /// from config files, [`ConcertoError::ConfigLoad`] if the files cannot be parsed
+    /// (running tasks are left untouched), or [`ConcertoError::ReloadIncomplete`] if
+    /// some tasks could not be updated (they keep their previous schedule).
+    pub async fn reload_config(&self) -> Result<(), ConcertoError> {
+        self.runtime.reload().await
+    }
+
+    /// Pause every task and wait up to `timeout` for runs in progress to finish
+    ///
+    /// Meant to run before a deploy or shutdown, so no run is cut off halfway.
+    /// Returns the tasks still running when `timeout` expired, empty once drained.
+    /// Tasks stay paused; `resume` them to undo a drain.
+    pub async fn drain(&self, timeout: Duration) -> Vec<String> {
+        self.runtime.drain(timeout).await
+    }
+
     /// Shutdown the scheduler and all interval tasks
-    pub async fn shutdown(mut self) -> Result<(), Box<dyn std::error::Error>> {
-        // Stop cron scheduler
-        self.cron_scheduler.shutdown().await?;
-        
-        // Abort all interval tasks
-        for handle in self.interval_handles {
-            handle.abort();
-        }
-        
-        Ok(())
+    pub async fn shutdown(self) -> Result<(), ConcertoError> {
+        self.runtime.shutdown().await
     }
 }
@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// Overall status of a [`HealthReport`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// Everything runs and no task needs attention
+    Healthy,
+    /// The scheduler runs, but some tasks are failing, stuck or overdue
+    Degraded,
+    /// The cron engine or an interval loop stopped; tasks will not run
+    Unhealthy,
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthStatus::Healthy => write!(f, "healthy"),
+            HealthStatus::Degraded => write!(f, "degraded"),
+            HealthStatus::Unhealthy => write!(f, "unhealthy"),
+        }
+    }
+}
+
+/// State of a running scheduler, from `SchedulerHandle::health`
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    /// The cron engine answered and no cron task missed a fire time
+    pub cron_engine_alive: bool,
+    /// Cron tasks that did not fire when due
+    pub missed_fires: Vec<String>,
+    /// Interval tasks whose loop stopped; they will not run again until rescheduled
+    pub dead_loops: Vec<String>,
+    /// Scheduled tasks whose last run failed or panicked
+    pub failing: Vec<String>,
+    /// Tasks with a run exceeding their `warn_after`
+    pub stuck: Vec<String>,
+    /// Tasks without a successful run within their `expect_success_within`
+    pub overdue: Vec<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl HealthReport {
+    /// Derive the status from the collected problems
+    pub(crate) fn new(
+        cron_engine_responds: bool,
+        missed_fires: Vec<String>,
+        dead_loops: Vec<String>,
+        failing: Vec<String>,
+        stuck: Vec<String>,
+        overdue: Vec<String>,
+    ) -> Self {
+        let cron_engine_alive = cron_engine_responds && missed_fires.is_empty();
+        let status = if !cron_engine_alive || !dead_loops.is_empty() {
+            HealthStatus::Unhealthy
+        } else if !failing.is_empty() || !stuck.is_empty() || !overdue.is_empty() {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+        Self {
+            status,
+            cron_engine_alive,
+            missed_fires,
+            dead_loops,
+            failing,
+            stuck,
+            overdue,
+            checked_at: Utc::now(),
+        }
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.status == HealthStatus::Healthy
+    }
+
+    /// Whether tasks are still being run, i.e. not `Unhealthy`; suited to liveness probes
+    pub fn is_alive(&self) -> bool {
+        self.status != HealthStatus::Unhealthy
+    }
+}
+
+impl fmt::Display for HealthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.status)?;
+        if !self.cron_engine_alive && self.missed_fires.is_empty() {
+            write!(f, "\n  [cron engine] not responding")?;
+        }
+        let sections = [
+            ("missed fire", &self.missed_fires),
+            ("dead loop", &self.dead_loops),
+            ("failing", &self.failing),
+            ("stuck", &self.stuck),
+            ("overdue", &self.overdue),
+        ];
+        for (label, tasks) in sections {
+            for task in tasks {
+                write!(f, "\n  [{}] {}", label, task)?;
+            }
+        }
+        Ok(())
+    }
+}
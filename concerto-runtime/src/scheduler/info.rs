@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::time::Duration;
+
+/// How a task was declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskKind {
+    /// `#[scheduled]` function
+    Function,
+    /// `#[scheduled]` method of a registered instance
+    Method,
+    /// `Runnable` registered as a task
+    Runnable,
+}
+
+impl fmt::Display for TaskKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskKind::Function => write!(f, "function"),
+            TaskKind::Method => write!(f, "method"),
+            TaskKind::Runnable => write!(f, "runnable"),
+        }
+    }
+}
+
+/// What a task is doing right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskState {
+    /// Scheduled and waiting for its next fire time
+    Idle,
+    /// At least one run is in progress
+    Running,
+    /// A run has been in progress for longer than the task's `warn_after`
+    Stuck,
+    /// Paused with `SchedulerHandle::pause`; fire times are skipped
+    Paused,
+    /// Not scheduled (disabled by config, profile or condition, or failed to register)
+    Disabled,
+}
+
+impl fmt::Display for TaskState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskState::Idle => write!(f, "idle"),
+            TaskState::Running => write!(f, "running"),
+            TaskState::Stuck => write!(f, "stuck"),
+            TaskState::Paused => write!(f, "paused"),
+            TaskState::Disabled => write!(f, "disabled"),
+        }
+    }
+}
+
+/// Outcome of a single run
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TaskOutcome {
+    Success,
+    /// The task returned an error, with its message
+    Failed(String),
+    /// The task panicked, with the panic message
+    Panicked(String),
+    /// The fire time passed without running the task, with the reason
+    Skipped(String),
+    /// The run exceeded the task's `timeout` and was cancelled, with that timeout
+    Timeout(Duration),
+}
+
+impl TaskOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, TaskOutcome::Success)
+    }
+
+    /// `success`, `failed`, `panicked`, `skipped` or `timeout`, without the message
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            TaskOutcome::Success => "success",
+            TaskOutcome::Failed(_) => "failed",
+            TaskOutcome::Panicked(_) => "panicked",
+            TaskOutcome::Skipped(_) => "skipped",
+            TaskOutcome::Timeout(_) => "timeout",
+        }
+    }
+}
+
+impl fmt::Display for TaskOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskOutcome::Success => write!(f, "success"),
+            TaskOutcome::Failed(message) => write!(f, "failed: {}", message),
+            TaskOutcome::Panicked(message) => write!(f, "panicked: {}", message),
+            TaskOutcome::Skipped(reason) => write!(f, "skipped: {}", reason),
+            TaskOutcome::Timeout(limit) => write!(f, "timed out after {}ms", limit.as_millis()),
+        }
+    }
+}
+
+/// Runtime view of a task, from `SchedulerHandle::task_info`
+#[derive(Debug, Clone)]
+pub struct TaskInfo {
+    pub name: String,
+    pub kind: TaskKind,
+    /// Resolved schedule, e.g. `fixed_rate 30000ms`; `None` while not scheduled
+    pub schedule: Option<String>,
+    /// Zone of a cron schedule
+    pub zone: Option<String>,
+    /// Whether `enabled`, profiles and condition allow the task to run and it is
+    /// scheduled; `false` when its schedule could not be launched
+    pub enabled: bool,
+    pub state: TaskState,
+    pub last_started: Option<DateTime<Utc>>,
+    pub last_finished: Option<DateTime<Utc>>,
+    pub last_outcome: Option<TaskOutcome>,
+    /// When the last successful run finished
+    pub last_success: Option<DateTime<Utc>>,
+    /// How late the last run started after its fire time
+    pub last_lateness: Option<Duration>,
+    /// Running time after which a run is reported as stuck
+    pub warn_after: Option<Duration>,
+    /// Running time after which a run is cancelled
+    pub timeout: Option<Duration>,
+    /// Longest time without a successful run before the task is overdue
+    pub expect_success_within: Option<Duration>,
+    /// No run succeeded within `expect_success_within`
+    pub overdue: bool,
+    /// `None` while not scheduled or paused
+    pub next_fire: Option<DateTime<Utc>>,
+    pub success_count: u64,
+    pub failure_count: u64,
+}
+
+/// One entry of a task's execution history, from `SchedulerHandle::history`
+#[derive(Debug, Clone)]
+pub struct TaskExecution {
+    /// When the schedule wanted the task to run
+    pub scheduled_at: DateTime<Utc>,
+    pub started_at: DateTime<Utc>,
+    /// Zero for skipped runs
+    pub duration: Duration,
+    pub outcome: TaskOutcome,
+}
+
+impl TaskExecution {
+    /// How late the run started after its fire time
+    ///
+    /// High values across tasks mean the runtime is overloaded.
+    pub fn lateness(&self) -> Duration {
+        (self.started_at - self.scheduled_at).to_std().unwrap_or_default()
+    }
+
+    /// Error or panic message of a failed run
+    pub fn error(&self) -> Option<&str> {
+        match &self.outcome {
+            TaskOutcome::Failed(message) | TaskOutcome::Panicked(message) => Some(message),
+            _ => None,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::task::ScheduledMethodMetadata;
+use crate::task::{ScheduledMethodMetadata, TaskFuture, TaskResult};
 use std::sync::Arc;
 
 /// Trait for instances that have scheduled methods
@@ -8,16 +8,41 @@ pub trait ScheduledInstance: Send + Sync + 'static {
     fn scheduled_methods() -> Vec<ScheduledMethodMetadata>;
     
     /// Call a scheduled method by name
-    fn call_scheduled_method(&self, method_name: &str) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>>;
+    fn call_scheduled_method(&self, method_name: &str) -> std::pin::Pin<Box<dyn std::future::Future<Output = TaskResult> + Send + '_>>;
 }
 
 /// Type alias for method caller function
-pub(crate) type MethodCaller = Arc<dyn Fn(&(dyn std::any::Any + Send + Sync), &str) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'static>> + Send + Sync>;
+pub(crate) type MethodCaller = Arc<dyn Fn(&(dyn std::any::Any + Send + Sync), &str) -> TaskFuture + Send + Sync>;
 
 /// Wrapper for a registered instance with scheduled methods
 pub(crate) struct RegisteredInstance {
     pub(crate) type_name: String,
+    /// Name given via `register_named`, also used as config prefix for `${.key}` placeholders
+    pub(crate) instance_name: Option<String>,
+    /// Call site of `register`/`register_named`, used in duplicate name reports
+    pub(crate) registered_at: &'static std::panic::Location<'static>,
     pub(crate) instance: Arc<dyn std::any::Any + Send + Sync>,
     pub(crate) methods: Vec<ScheduledMethodMetadata>,
     pub(crate) caller: MethodCaller,
 }
+
+impl RegisteredInstance {
+    /// Task name of a method
+    ///
+    /// - `register`: explicit `name`, or `{type_name}::{method}`
+    /// - `register_named`: `{instance_name}::{name or method}`
+    pub(crate) fn task_name(&self, method: &ScheduledMethodMetadata) -> String {
+        match (&self.instance_name, method.name) {
+            (Some(instance_name), name) => {
+                format!("{}::{}", instance_name, name.unwrap_or(method.method_name))
+            }
+            (None, Some(name)) => name.to_string(),
+            (None, None) => format!("{}::{}", self.type_name, method.method_name),
+        }
+    }
+
+    /// Config prefix for relative placeholders
+    pub(crate) fn config_prefix(&self) -> Option<&str> {
+        self.instance_name.as_deref()
+    }
+}
@@ -0,0 +1,101 @@
+use super::definition::panic_message;
+use super::event::SchedulerEvent;
+use super::info::TaskExecution;
+use chrono::{DateTime, Utc};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::error;
+
+/// Callbacks for task lifecycle events, registered with `SchedulerBuilder::listener`
+///
+/// Every method has an empty default, so implement only the ones you need.
+/// Callbacks run inline on the task's execution path: keep them short and
+/// spawn anything slow. A panicking listener is logged and does not affect the task.
+///
+/// # Example
+///
+/// ```rust
+/// use concerto_runtime::{SchedulerBuilder, TaskExecution, TaskListener};
+///
+/// struct Alerting;
+///
+/// impl TaskListener for Alerting {
+///     fn on_failure(&self, task: &str, execution: &TaskExecution) {
+///         eprintln!("ALERT {} failed: {}", task, execution.error().unwrap_or_default());
+///     }
+/// }
+///
+/// let scheduler = SchedulerBuilder::new().listener(Alerting).build();
+/// ```
+pub trait TaskListener: Send + Sync + 'static {
+    /// The task was scheduled, at start or by a config reload
+    fn on_scheduled(&self, task: &str, schedule: &str) {
+        let _ = (task, schedule);
+    }
+
+    /// A run is about to start; `scheduled_at` is the fire time it belongs to
+    fn on_start(&self, task: &str, scheduled_at: DateTime<Utc>) {
+        let _ = (task, scheduled_at);
+    }
+
+    /// A run completed successfully
+    fn on_success(&self, task: &str, execution: &TaskExecution) {
+        let _ = (task, execution);
+    }
+
+    /// A run returned an error or panicked; see [`TaskExecution::error`]
+    fn on_failure(&self, task: &str, execution: &TaskExecution) {
+        let _ = (task, execution);
+    }
+
+    /// A fire time passed without running the task because it is paused
+    ///
+    /// Runs that overlap are not skipped: every fire of a cron or `fixed_rate` task starts a run.
+    fn on_skipped(&self, task: &str, execution: &TaskExecution) {
+        let _ = (task, execution);
+    }
+
+    /// A run exceeded the task's `timeout` and was cancelled
+    fn on_timeout(&self, task: &str, execution: &TaskExecution) {
+        let _ = (task, execution);
+    }
+}
+
+/// Delivers task events to the registered listeners and to `subscribe()` receivers
+pub(crate) struct Notifier {
+    listeners: Vec<Arc<dyn TaskListener>>,
+    events: broadcast::Sender<SchedulerEvent>,
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Self::new(Vec::new(), broadcast::channel(1).0)
+    }
+}
+
+impl Notifier {
+    pub(crate) fn new(listeners: Vec<Arc<dyn TaskListener>>, events: broadcast::Sender<SchedulerEvent>) -> Self {
+        Self { listeners, events }
+    }
+
+    /// Call every listener, logging listener panics instead of propagating them
+    pub(crate) fn notify(&self, f: impl Fn(&dyn TaskListener)) {
+        for listener in &self.listeners {
+            if let Err(payload) = catch_unwind(AssertUnwindSafe(|| f(listener.as_ref()))) {
+                error!(panic = %panic_message(payload), "Task listener panicked");
+            }
+        }
+    }
+
+    /// Broadcast an event; only built when someone is subscribed
+    pub(crate) fn emit(&self, event: impl FnOnce() -> SchedulerEvent) {
+        if self.events.receiver_count() > 0 {
+            let _ = self.events.send(event());
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<SchedulerEvent> {
+        self.events.subscribe()
+    }
+}
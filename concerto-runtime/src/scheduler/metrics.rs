@@ -0,0 +1,248 @@
+use super::info::TaskKind;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Content type to serve [`SchedulerHandle::render_metrics`](crate::SchedulerHandle::render_metrics) with
+pub const OPENMETRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Upper bounds of the execution duration buckets, in seconds
+const DURATION_BUCKETS: [f64; 14] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0,
+];
+
+/// Upper bounds of the fire lateness buckets, in seconds
+const LATENESS_BUCKETS: [f64; 10] = [0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 60.0];
+
+/// Histogram with fixed buckets; counts are per bucket, made cumulative when rendered
+#[derive(Debug, Clone)]
+pub(crate) struct Histogram<const N: usize> {
+    bounds: &'static [f64; N],
+    counts: [u64; N],
+    sum: f64,
+    count: u64,
+}
+
+impl<const N: usize> Histogram<N> {
+    const fn new(bounds: &'static [f64; N]) -> Self {
+        Self { bounds, counts: [0; N], sum: 0.0, count: 0 }
+    }
+
+    fn observe(&mut self, value: Duration) {
+        let seconds = value.as_secs_f64();
+        if let Some(bucket) = self.bounds.iter().position(|bound| seconds <= *bound) {
+            self.counts[bucket] += 1;
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+/// Metrics of a single task, kept next to its run stats
+#[derive(Debug, Clone)]
+pub(crate) struct TaskMetrics {
+    pub(crate) skips: u64,
+    pub(crate) timeouts: u64,
+    duration: Histogram<14>,
+    lateness: Histogram<10>,
+}
+
+impl Default for TaskMetrics {
+    fn default() -> Self {
+        Self {
+            skips: 0,
+            timeouts: 0,
+            duration: Histogram::new(&DURATION_BUCKETS),
+            lateness: Histogram::new(&LATENESS_BUCKETS),
+        }
+    }
+}
+
+impl TaskMetrics {
+    /// Record a finished run; `lateness` is how long after its fire time it started
+    pub(crate) fn observe_run(&mut self, duration: Duration, lateness: Duration) {
+        self.duration.observe(duration);
+        self.lateness.observe(lateness);
+    }
+}
+
+/// Values of one task at render time
+pub(crate) struct TaskSample {
+    pub(crate) name: String,
+    pub(crate) kind: TaskKind,
+    pub(crate) runs: u64,
+    pub(crate) failures: u64,
+    pub(crate) in_flight: u32,
+    pub(crate) metrics: TaskMetrics,
+}
+
+/// Render every task's metrics in the OpenMetrics text format
+pub(crate) fn render(samples: &[TaskSample]) -> String {
+    let mut out = String::new();
+
+    family(&mut out, "concerto_task_runs", "counter", None, "Completed task runs.");
+    for sample in samples {
+        line(&mut out, "concerto_task_runs_total", sample, None, sample.runs);
+    }
+    family(
+        &mut out,
+        "concerto_task_failures",
+        "counter",
+        None,
+        "Task runs that returned an error, panicked or timed out; timeouts are also in concerto_task_timeouts_total.",
+    );
+    for sample in samples {
+        line(&mut out, "concerto_task_failures_total", sample, None, sample.failures);
+    }
+    family(&mut out, "concerto_task_skips", "counter", None, "Fire times skipped without running the task.");
+    for sample in samples {
+        line(&mut out, "concerto_task_skips_total", sample, None, sample.metrics.skips);
+    }
+    family(&mut out, "concerto_task_timeouts", "counter", None, "Task runs cancelled after exceeding their timeout.");
+    for sample in samples {
+        line(&mut out, "concerto_task_timeouts_total", sample, None, sample.metrics.timeouts);
+    }
+    family(&mut out, "concerto_task_in_flight", "gauge", None, "Task runs in progress.");
+    for sample in samples {
+        line(&mut out, "concerto_task_in_flight", sample, None, sample.in_flight);
+    }
+    family(&mut out, "concerto_task_duration_seconds", "histogram", Some("seconds"), "Task run duration.");
+    for sample in samples {
+        histogram(&mut out, "concerto_task_duration_seconds", sample, &sample.metrics.duration);
+    }
+    family(
+        &mut out,
+        "concerto_task_lateness_seconds",
+        "histogram",
+        Some("seconds"),
+        "Delay between a fire time and the run start.",
+    );
+    for sample in samples {
+        histogram(&mut out, "concerto_task_lateness_seconds", sample, &sample.metrics.lateness);
+    }
+
+    out.push_str("# EOF\n");
+    out
+}
+
+fn family(out: &mut String, name: &str, kind: &str, unit: Option<&str>, help: &str) {
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    if let Some(unit) = unit {
+        let _ = writeln!(out, "# UNIT {} {}", name, unit);
+    }
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+}
+
+fn line(out: &mut String, name: &str, sample: &TaskSample, le: Option<&str>, value: impl std::fmt::Display) {
+    let _ = write!(out, "{}{{task=\"{}\",kind=\"{}\"", name, escape(&sample.name), sample.kind);
+    if let Some(le) = le {
+        let _ = write!(out, ",le=\"{}\"", le);
+    }
+    let _ = writeln!(out, "}} {}", value);
+}
+
+fn histogram<const N: usize>(out: &mut String, name: &str, sample: &TaskSample, histogram: &Histogram<N>) {
+    let bucket = format!("{}_bucket", name);
+    let mut cumulative = 0;
+    for (bound, count) in histogram.bounds.iter().zip(histogram.counts) {
+        cumulative += count;
+        line(out, &bucket, sample, Some(&format!("{:?}", bound)), cumulative);
+    }
+    line(out, &bucket, sample, Some("+Inf"), histogram.count);
+    line(out, &format!("{}_sum", name), sample, None, histogram.sum);
+    line(out, &format!("{}_count", name), sample, None, histogram.count);
+}
+
+/// Escape a label value (`\`, `"` and newlines)
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(name: &str, kind: TaskKind) -> TaskSample {
+        TaskSample { name: name.to_string(), kind, runs: 0, failures: 0, in_flight: 0, metrics: TaskMetrics::default() }
+    }
+
+    #[test]
+    fn renders_openmetrics_text() {
+        let mut task = sample("say \"hi\"\\\nbye", TaskKind::Function);
+        task.metrics.observe_run(Duration::from_millis(3), Duration::from_millis(2));
+        task.metrics.observe_run(Duration::from_millis(200), Duration::ZERO);
+        // Beyond the last bound: only counted in `+Inf`
+        task.metrics.observe_run(Duration::from_secs(400), Duration::from_secs(90));
+        task.metrics.skips = 2;
+        task.metrics.timeouts = 1;
+        (task.runs, task.failures, task.in_flight) = (3, 1, 1);
+
+        let expected = r#"# TYPE concerto_task_runs counter
+# HELP concerto_task_runs Completed task runs.
+concerto_task_runs_total{task="say \"hi\"\\\nbye",kind="function"} 3
+# TYPE concerto_task_failures counter
+# HELP concerto_task_failures Task runs that returned an error, panicked or timed out; timeouts are also in concerto_task_timeouts_total.
+concerto_task_failures_total{task="say \"hi\"\\\nbye",kind="function"} 1
+# TYPE concerto_task_skips counter
+# HELP concerto_task_skips Fire times skipped without running the task.
+concerto_task_skips_total{task="say \"hi\"\\\nbye",kind="function"} 2
+# TYPE concerto_task_timeouts counter
+# HELP concerto_task_timeouts Task runs cancelled after exceeding their timeout.
+concerto_task_timeouts_total{task="say \"hi\"\\\nbye",kind="function"} 1
+# TYPE concerto_task_in_flight gauge
+# HELP concerto_task_in_flight Task runs in progress.
+concerto_task_in_flight{task="say \"hi\"\\\nbye",kind="function"} 1
+# TYPE concerto_task_duration_seconds histogram
+# UNIT concerto_task_duration_seconds seconds
+# HELP concerto_task_duration_seconds Task run duration.
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.005"} 1
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.01"} 1
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.025"} 1
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.05"} 1
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.1"} 1
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.25"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.5"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="1.0"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="2.5"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="5.0"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="10.0"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="30.0"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="60.0"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="300.0"} 2
+concerto_task_duration_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="+Inf"} 3
+concerto_task_duration_seconds_sum{task="say \"hi\"\\\nbye",kind="function"} 400.203
+concerto_task_duration_seconds_count{task="say \"hi\"\\\nbye",kind="function"} 3
+# TYPE concerto_task_lateness_seconds histogram
+# UNIT concerto_task_lateness_seconds seconds
+# HELP concerto_task_lateness_seconds Delay between a fire time and the run start.
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.001"} 1
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.005"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.01"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.05"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.1"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="0.5"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="1.0"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="5.0"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="10.0"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="60.0"} 2
+concerto_task_lateness_seconds_bucket{task="say \"hi\"\\\nbye",kind="function",le="+Inf"} 3
+concerto_task_lateness_seconds_sum{task="say \"hi\"\\\nbye",kind="function"} 90.002
+concerto_task_lateness_seconds_count{task="say \"hi\"\\\nbye",kind="function"} 3
+# EOF
+"#;
+        assert_eq!(render(&[task]), expected);
+    }
+
+    #[test]
+    fn every_family_lists_all_tasks_once() {
+        let rendered = render(&[sample("a", TaskKind::Method), sample("b", TaskKind::Runnable)]);
+        assert_eq!(rendered.matches("# TYPE ").count(), 7);
+        assert_eq!(rendered.matches("# UNIT ").count(), 2);
+        assert!(rendered.contains(
+            "concerto_task_runs_total{task=\"a\",kind=\"method\"} 0\n\
+             concerto_task_runs_total{task=\"b\",kind=\"runnable\"} 0\n"
+        ));
+        assert!(rendered.contains("concerto_task_duration_seconds_sum{task=\"b\",kind=\"runnable\"} 0\n"));
+        assert!(rendered.ends_with("# EOF\n"));
+        assert_eq!(render(&[]).lines().last(), Some("# EOF"));
+    }
+}
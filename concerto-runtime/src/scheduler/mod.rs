@@ -1,9 +1,34 @@
+mod definition;
+mod event;
 mod handle;
+mod health;
+mod info;
 mod instance;
+mod listener;
+#[cfg(feature = "metrics")]
+mod metrics;
 mod builder;
+mod report;
+mod runner;
+mod runtime;
+#[allow(clippy::module_inception)]
 mod scheduler;
+mod span;
 
+pub use event::SchedulerEvent;
 pub use handle::SchedulerHandle;
+pub use health::{HealthReport, HealthStatus};
+pub use info::{TaskExecution, TaskInfo, TaskKind, TaskOutcome, TaskState};
 pub use instance::ScheduledInstance;
+pub use listener::TaskListener;
+#[cfg(feature = "metrics")]
+pub use metrics::OPENMETRICS_CONTENT_TYPE;
 pub use builder::SchedulerBuilder;
+pub use report::{
+    DisabledTask, FailedTask, RegisteredTask, StartReport, TaskValidation, ValidationOutcome,
+    ValidationReport,
+};
 pub use scheduler::Scheduler;
+#[cfg(any(feature = "admin-http", all(feature = "control-socket", unix)))]
+pub(crate) use runtime::SchedulerRuntime;
+pub use span::SpanLayout;
@@ -0,0 +1,126 @@
+use crate::config::ResolvedValue;
+use crate::error::ConcertoError;
+use std::fmt;
+
+/// Outcome of `Scheduler::start` for every task
+///
+/// Available from [`SchedulerHandle::start_report`](super::SchedulerHandle::start_report),
+/// or inside [`ConcertoError::StartFailed`] when the builder was set to `fail_fast(true)`.
+#[derive(Debug, Default)]
+pub struct StartReport {
+    /// Tasks that were scheduled
+    pub registered: Vec<RegisteredTask>,
+    /// Tasks skipped on purpose (profile not active, `enabled` false, condition not met)
+    pub disabled: Vec<DisabledTask>,
+    /// Tasks that could not be scheduled
+    pub failed: Vec<FailedTask>,
+}
+
+/// A task that was scheduled
+#[derive(Debug, Clone)]
+pub struct RegisteredTask {
+    pub name: String,
+    /// Resolved schedule, e.g. `cron '0 */5 * * * *' (Local)` or `fixed_rate 30000ms`
+    pub schedule: String,
+}
+
+/// A task that was not scheduled on purpose
+#[derive(Debug, Clone)]
+pub struct DisabledTask {
+    pub name: String,
+    pub reason: String,
+}
+
+/// A task that could not be scheduled
+#[derive(Debug)]
+pub struct FailedTask {
+    pub name: String,
+    pub error: ConcertoError,
+}
+
+impl StartReport {
+    /// Whether every task that should run was scheduled
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl fmt::Display for StartReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} registered, {} disabled, {} failed",
+            self.registered.len(),
+            self.disabled.len(),
+            self.failed.len()
+        )?;
+        for task in &self.registered {
+            write!(f, "\n  [registered] {}: {}", task.name, task.schedule)?;
+        }
+        for task in &self.disabled {
+            write!(f, "\n  [disabled] {}: {}", task.name, task.reason)?;
+        }
+        for task in &self.failed {
+            write!(f, "\n  [failed] {}: {}", task.name, task.error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of `Scheduler::validate` for every task
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub tasks: Vec<TaskValidation>,
+}
+
+/// Validation result of a single task
+#[derive(Debug)]
+pub struct TaskValidation {
+    pub name: String,
+    pub outcome: ValidationOutcome,
+    /// Every placeholder the task uses, with its value and where it came from
+    pub values: Vec<ResolvedValue>,
+}
+
+/// Whether a task would be scheduled
+#[derive(Debug)]
+pub enum ValidationOutcome {
+    /// The task would be scheduled with this schedule
+    Valid { schedule: String },
+    /// The task would be skipped on purpose
+    Disabled { reason: String },
+    /// The task would fail to register
+    Invalid(ConcertoError),
+}
+
+impl ValidationReport {
+    /// Whether every task is valid or disabled on purpose
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// Name and error of every invalid task
+    pub fn errors(&self) -> impl Iterator<Item = (&str, &ConcertoError)> {
+        self.tasks.iter().filter_map(|task| match &task.outcome {
+            ValidationOutcome::Invalid(error) => Some((task.name.as_str(), error)),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} task(s) checked, {} invalid", self.tasks.len(), self.errors().count())?;
+        for task in &self.tasks {
+            match &task.outcome {
+                ValidationOutcome::Valid { schedule } => write!(f, "\n  [valid] {}: {}", task.name, schedule)?,
+                ValidationOutcome::Disabled { reason } => write!(f, "\n  [disabled] {}: {}", task.name, reason)?,
+                ValidationOutcome::Invalid(error) => write!(f, "\n  [invalid] {}: {}", task.name, error)?,
+            }
+            for value in &task.values {
+                write!(f, "\n      {} = '{}' ({})", value.placeholder, value.value, value.source)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,372 @@
+use super::definition::TaskAction;
+use super::info::{TaskExecution, TaskOutcome};
+use super::event::SchedulerEvent;
+use super::listener::Notifier;
+use super::runtime::RuntimeOptions;
+use super::span::{record_finish, task_span, SpanLayout};
+#[cfg(feature = "metrics")]
+use super::metrics::{TaskMetrics, TaskSample};
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, warn, Instrument};
+
+/// Runs a task when it fires and keeps track of its runs
+///
+/// Shared by the cron job or interval loop of the task; kept across reschedules
+/// so counters and history survive config reloads.
+pub(crate) struct TaskRunner {
+    pub(crate) name: String,
+    pub(crate) action: TaskAction,
+    paused: AtomicBool,
+    /// Maximum number of history entries, 0 to keep none
+    history_size: usize,
+    span_layout: SpanLayout,
+    lateness_warning: Option<Duration>,
+    notifier: Arc<Notifier>,
+    state: Mutex<RunState>,
+}
+
+#[derive(Default)]
+struct RunState {
+    stats: RunStats,
+    /// Runs in progress, oldest first
+    in_flight: Vec<InFlightRun>,
+    next_run_id: u64,
+    /// Oldest first; allocated on the first run
+    history: VecDeque<TaskExecution>,
+    /// Running time after which a run is cancelled, from the current schedule
+    timeout: Option<Duration>,
+    #[cfg(feature = "metrics")]
+    metrics: TaskMetrics,
+}
+
+/// A run in progress, watched for running too long
+struct InFlightRun {
+    id: u64,
+    started_at: DateTime<Utc>,
+    clock: Instant,
+    /// Already reported as stuck
+    stuck: bool,
+}
+
+/// Counters and timestamps of a task's runs
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RunStats {
+    /// Runs in progress (fixed_rate and cron runs may overlap)
+    pub(crate) running: u32,
+    /// Fire time of the last fire, run or skipped
+    pub(crate) last_fired: Option<DateTime<Utc>>,
+    pub(crate) last_started: Option<DateTime<Utc>>,
+    pub(crate) last_finished: Option<DateTime<Utc>>,
+    pub(crate) last_outcome: Option<TaskOutcome>,
+    pub(crate) last_lateness: Option<Duration>,
+    /// When the last successful run finished
+    pub(crate) last_success: Option<DateTime<Utc>>,
+    /// No run succeeded within `expect_success_within`
+    pub(crate) overdue: bool,
+    /// A run in progress exceeded its `warn_after`
+    pub(crate) stuck: bool,
+    pub(crate) success_count: u64,
+    pub(crate) failure_count: u64,
+}
+
+impl TaskRunner {
+    pub(crate) fn new(name: String, action: TaskAction, options: &RuntimeOptions) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            action,
+            paused: AtomicBool::new(false),
+            history_size: options.history_size,
+            span_layout: options.span_layout,
+            lateness_warning: options.lateness_warning,
+            notifier: options.notifier.clone(),
+            state: Mutex::new(RunState::default()),
+        })
+    }
+
+    /// Run the task once and record the outcome; skipped while paused
+    ///
+    /// `scheduled_at` is the fire time the run belongs to.
+    pub(crate) async fn fire(&self, scheduled_at: DateTime<Utc>) {
+        self.notifier.emit(|| SchedulerEvent::TaskFired {
+            task: self.name.clone(),
+            scheduled_at,
+            at: Utc::now(),
+        });
+        self.update(|state| state.stats.last_fired = Some(scheduled_at));
+
+        if self.is_paused() {
+            debug!(task_name = %self.name, "Task paused, skipping run");
+            let execution = TaskExecution {
+                scheduled_at,
+                started_at: Utc::now(),
+                duration: Duration::ZERO,
+                outcome: TaskOutcome::Skipped("paused".to_string()),
+            };
+            self.notifier.notify(|listener| listener.on_skipped(&self.name, &execution));
+            self.update(|state| {
+                #[cfg(feature = "metrics")]
+                {
+                    state.metrics.skips += 1;
+                }
+                self.record(state, execution);
+            });
+            return;
+        }
+
+        self.run(scheduled_at).await;
+    }
+
+    /// Run the task once, even while paused, and record the outcome
+    pub(crate) async fn run(&self, scheduled_at: DateTime<Utc>) {
+        let started_at = Utc::now();
+        let clock = Instant::now();
+        let lateness = (started_at - scheduled_at).to_std().unwrap_or_default();
+        let mut run_id = 0;
+        self.update(|state| {
+            run_id = state.next_run_id;
+            state.next_run_id += 1;
+            state.in_flight.push(InFlightRun { id: run_id, started_at, clock, stuck: false });
+            state.stats.running += 1;
+            state.stats.last_started = Some(started_at);
+            state.stats.last_lateness = Some(lateness);
+        });
+        if self.lateness_warning.is_some_and(|threshold| lateness > threshold) {
+            warn!(
+                task_name = %self.name,
+                scheduled_at = %scheduled_at,
+                lateness_ms = lateness.as_millis() as u64,
+                "Task started late, the runtime may be overloaded"
+            );
+        }
+        self.notifier.notify(|listener| listener.on_start(&self.name, scheduled_at));
+        self.notifier.emit(|| SchedulerEvent::TaskStarted {
+            task: self.name.clone(),
+            scheduled_at,
+            at: started_at,
+        });
+
+        let timeout = self.lock().timeout;
+        let span = task_span(self.span_layout, &self.name, self.action.kind(), scheduled_at);
+        let outcome = self.action.run(timeout).instrument(span.clone()).await;
+        let duration = clock.elapsed();
+        span.in_scope(|| match &outcome {
+            TaskOutcome::Failed(message) => warn!(task_name = %self.name, error = %message, "Task failed"),
+            TaskOutcome::Panicked(message) => error!(task_name = %self.name, panic = %message, "Task panicked"),
+            TaskOutcome::Timeout(limit) => {
+                warn!(task_name = %self.name, timeout_ms = limit.as_millis() as u64, "Task timed out")
+            }
+            _ => {}
+        });
+        record_finish(&span, self.span_layout, duration, &outcome);
+        drop(span);
+
+        self.update(|state| {
+            state.in_flight.retain(|run| run.id != run_id);
+            let stats = &mut state.stats;
+            let finished_at = Utc::now();
+            stats.running = stats.running.saturating_sub(1);
+            stats.last_finished = Some(finished_at);
+            if outcome.is_success() {
+                stats.last_success = Some(finished_at);
+                stats.overdue = false;
+                stats.success_count += 1;
+            } else {
+                stats.failure_count += 1;
+            }
+            stats.last_outcome = Some(outcome.clone());
+            #[cfg(feature = "metrics")]
+            {
+                if matches!(outcome, TaskOutcome::Timeout(_)) {
+                    state.metrics.timeouts += 1;
+                }
+                state.metrics.observe_run(duration, lateness);
+            }
+        });
+
+        let execution = TaskExecution { scheduled_at, started_at, duration, outcome };
+        if execution.outcome.is_success() {
+            self.notifier.notify(|listener| listener.on_success(&self.name, &execution));
+            self.notifier.emit(|| SchedulerEvent::TaskCompleted {
+                task: self.name.clone(),
+                execution: execution.clone(),
+                at: Utc::now(),
+            });
+        } else {
+            if matches!(execution.outcome, TaskOutcome::Timeout(_)) {
+                self.notifier.notify(|listener| listener.on_timeout(&self.name, &execution));
+            } else {
+                self.notifier.notify(|listener| listener.on_failure(&self.name, &execution));
+            }
+            self.notifier.emit(|| SchedulerEvent::TaskFailed {
+                task: self.name.clone(),
+                execution: execution.clone(),
+                at: Utc::now(),
+            });
+        }
+        self.update(|state| self.record(state, execution));
+    }
+
+    /// Announce that the task now runs on `schedule`, replacing `previous` if any
+    pub(crate) fn scheduled(&self, schedule: &str, previous: Option<&str>) {
+        self.notifier.notify(|listener| listener.on_scheduled(&self.name, schedule));
+        self.notifier.emit(|| match previous {
+            Some(previous) => SchedulerEvent::TaskRescheduled {
+                task: self.name.clone(),
+                old_schedule: previous.to_string(),
+                new_schedule: schedule.to_string(),
+                at: Utc::now(),
+            },
+            None => SchedulerEvent::TaskRegistered {
+                task: self.name.clone(),
+                schedule: schedule.to_string(),
+                at: Utc::now(),
+            },
+        });
+    }
+
+    /// Announce that the task is not scheduled
+    pub(crate) fn disabled(&self, reason: &str) {
+        self.notifier.emit(|| SchedulerEvent::TaskDisabled {
+            task: self.name.clone(),
+            reason: reason.to_string(),
+            at: Utc::now(),
+        });
+    }
+
+    /// Apply the `timeout` of a new schedule to the runs started from now on
+    pub(crate) fn set_timeout(&self, timeout: Option<Duration>) {
+        self.update(|state| state.timeout = timeout);
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Pause or resume; returns whether the task was paused before
+    pub(crate) fn set_paused(&self, paused: bool) -> bool {
+        let was_paused = self.paused.swap(paused, Ordering::Relaxed);
+        if was_paused != paused {
+            let (task, at) = (self.name.clone(), Utc::now());
+            self.notifier.emit(|| match paused {
+                true => SchedulerEvent::TaskPaused { task, at },
+                false => SchedulerEvent::TaskResumed { task, at },
+            });
+        }
+        was_paused
+    }
+
+    pub(crate) fn stats(&self) -> RunStats {
+        let state = self.lock();
+        RunStats {
+            stuck: state.in_flight.iter().any(|run| run.stuck),
+            ..state.stats.clone()
+        }
+    }
+
+    /// Report the task as overdue when no run succeeded within `window`
+    ///
+    /// Counted from the last success, or from `since` if the task never succeeded.
+    /// Reported once until the task succeeds again.
+    pub(crate) fn check_overdue(&self, window: Duration, since: DateTime<Utc>, now: DateTime<Utc>) {
+        let mut last_success = None;
+        let mut newly_overdue = false;
+        self.update(|state| {
+            let stats = &mut state.stats;
+            let elapsed = (now - stats.last_success.unwrap_or(since)).to_std().unwrap_or_default();
+            if elapsed > window && !stats.overdue {
+                stats.overdue = true;
+                newly_overdue = true;
+                last_success = stats.last_success;
+            }
+        });
+        if !newly_overdue {
+            return;
+        }
+
+        warn!(
+            task_name = %self.name,
+            last_success = ?last_success,
+            expect_success_within_ms = window.as_millis() as u64,
+            "Task is overdue, no successful run within the expected window"
+        );
+        self.notifier.emit(|| SchedulerEvent::Overdue {
+            task: self.name.clone(),
+            last_success,
+            expected_within: window,
+            at: now,
+        });
+    }
+
+    /// Report runs in progress for longer than `limit`, once per run
+    pub(crate) fn check_stuck(&self, limit: Duration) {
+        let mut stuck = Vec::new();
+        self.update(|state| {
+            for run in state.in_flight.iter_mut().filter(|run| !run.stuck) {
+                let elapsed = run.clock.elapsed();
+                if elapsed > limit {
+                    run.stuck = true;
+                    stuck.push((run.started_at, elapsed));
+                }
+            }
+        });
+
+        for (started_at, elapsed) in stuck {
+            warn!(
+                task_name = %self.name,
+                started_at = %started_at,
+                elapsed_ms = elapsed.as_millis() as u64,
+                warn_after_ms = limit.as_millis() as u64,
+                "Task is running longer than expected, it may be stuck"
+            );
+            self.notifier.emit(|| SchedulerEvent::TaskStuck {
+                task: self.name.clone(),
+                started_at,
+                elapsed,
+                at: Utc::now(),
+            });
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) fn metrics_sample(&self) -> TaskSample {
+        let state = self.lock();
+        TaskSample {
+            name: self.name.clone(),
+            kind: self.action.kind(),
+            runs: state.stats.success_count + state.stats.failure_count,
+            failures: state.stats.failure_count,
+            in_flight: state.stats.running,
+            metrics: state.metrics.clone(),
+        }
+    }
+
+    /// Last runs, oldest first
+    pub(crate) fn history(&self) -> Vec<TaskExecution> {
+        self.lock().history.iter().cloned().collect()
+    }
+
+    /// Append to the history, dropping the oldest entry once full
+    fn record(&self, state: &mut RunState, execution: TaskExecution) {
+        if self.history_size == 0 {
+            return;
+        }
+        if state.history.len() == self.history_size {
+            state.history.pop_front();
+        } else if state.history.capacity() == 0 {
+            state.history.reserve_exact(self.history_size);
+        }
+        state.history.push_back(execution);
+    }
+
+    fn update(&self, f: impl FnOnce(&mut RunState)) {
+        f(&mut self.lock());
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, RunState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,1064 @@
+use super::definition::{ResolvedSchedule, TaskDefinition, Trigger};
+use super::info::{TaskExecution, TaskInfo, TaskOutcome, TaskState};
+use super::event::SchedulerEvent;
+use super::health::HealthReport;
+use super::listener::Notifier;
+use super::report::{DisabledTask, FailedTask, RegisteredTask, StartReport};
+use super::runner::TaskRunner;
+use super::span::SpanLayout;
+use crate::config::ConfigLoader;
+use crate::error::ConcertoError;
+use crate::schedule::{Schedule, Zone};
+use config::Config;
+use chrono::{DateTime, Utc};
+use std::path::PathBuf;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, SystemTime};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio_cron_scheduler::{Job, JobScheduler, JobSchedulerError};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// A task scheduled on the cron scheduler or as a tokio interval loop
+enum ScheduledJob {
+    Cron {
+        uuid: Uuid,
+        /// When the job was added; earlier fire times are not expected
+        since: DateTime<Utc>,
+    },
+    Interval {
+        handle: JoinHandle<()>,
+        /// When the loop runs the task for the first time
+        first_run: DateTime<Utc>,
+    },
+}
+
+/// A job built from a resolved schedule, not started yet
+enum PreparedJob {
+    Cron(Job),
+    Interval {
+        runner: Arc<TaskRunner>,
+        interval_millis: u64,
+        initial_delay_millis: u64,
+        is_fixed_delay: bool,
+    },
+}
+
+/// A task and its current schedule (`None` while disabled)
+struct TaskSlot {
+    definition: TaskDefinition,
+    runner: Arc<TaskRunner>,
+    /// Whether `enabled`, profiles and condition allow the task to run and its job
+    /// was launched; set once the launch succeeded
+    enabled: bool,
+    schedule: Option<ResolvedSchedule>,
+    job: Option<ScheduledJob>,
+    /// Resolved `expect_success_within`, watched even while disabled
+    expect_success_within: Option<Duration>,
+}
+
+impl TaskSlot {
+    fn new(definition: TaskDefinition, options: &RuntimeOptions) -> Self {
+        let runner = TaskRunner::new(definition.name.clone(), definition.action.clone(), options);
+        Self {
+            definition,
+            runner,
+            enabled: false,
+            schedule: None,
+            job: None,
+            expect_success_within: None,
+        }
+    }
+
+    /// Next `n` fire times from `now`; empty while not scheduled
+    fn fire_times(&self, now: DateTime<Utc>, n: usize) -> Vec<DateTime<Utc>> {
+        let (Some(schedule), Some(job)) = (&self.schedule, &self.job) else {
+            return Vec::new();
+        };
+        let Ok(preview) = schedule.trigger.schedule() else {
+            return Vec::new();
+        };
+
+        match (job, preview.interval()) {
+            (ScheduledJob::Interval { first_run, .. }, Some(interval)) => {
+                if now < *first_run {
+                    let mut times = vec![*first_run];
+                    times.extend(preview.upcoming(*first_run, n.saturating_sub(1)));
+                    times.truncate(n);
+                    return times;
+                }
+                if matches!(schedule.trigger, Trigger::FixedDelay { .. }) {
+                    // Counted from the end of the last run
+                    let last_finished = self.runner.stats().last_finished.filter(|last| last >= first_run);
+                    let last_finished = last_finished.unwrap_or(*first_run);
+                    return preview.upcoming(last_finished, n);
+                }
+                let interval_millis = (interval.as_millis() as i64).max(1);
+                let elapsed_runs = (now - *first_run).num_milliseconds() / interval_millis;
+                let last_run = *first_run + chrono::Duration::milliseconds(elapsed_runs * interval_millis);
+                preview.upcoming(last_run, n)
+            }
+            _ => preview.upcoming(now, n),
+        }
+    }
+
+    /// `warn_after` of the task, or `default` if it sets none
+    fn warn_after(&self, default: Option<Duration>) -> Option<Duration> {
+        self.schedule.as_ref().and_then(|schedule| schedule.warn_after).or(default)
+    }
+
+    /// Whether a cron fire time due more than `CRON_GRACE` ago did not fire
+    fn missed_cron_fire(&self, now: DateTime<Utc>, since: DateTime<Utc>) -> bool {
+        let Some(Ok(schedule)) = self.schedule.as_ref().map(|schedule| schedule.trigger.schedule()) else {
+            return false;
+        };
+        let Some(due) = schedule.last_fire_time(now - CRON_GRACE) else {
+            return false;
+        };
+        due > since && self.runner.stats().last_fired.is_none_or(|fired| fired < due)
+    }
+
+    fn info(&self, now: DateTime<Utc>, default_warn_after: Option<Duration>) -> TaskInfo {
+        let stats = self.runner.stats();
+        let paused = self.runner.is_paused();
+        let state = if self.job.is_none() {
+            TaskState::Disabled
+        } else if paused {
+            TaskState::Paused
+        } else if stats.stuck {
+            TaskState::Stuck
+        } else if stats.running > 0 {
+            TaskState::Running
+        } else {
+            TaskState::Idle
+        };
+        let zone = match self.schedule.as_ref().map(|schedule| &schedule.trigger) {
+            Some(Trigger::Cron { zone, .. }) => Zone::parse(zone).ok().map(|zone| zone.to_string()),
+            _ => None,
+        };
+        let next_fire = if paused { None } else { self.fire_times(now, 1).pop() };
+
+        TaskInfo {
+            name: self.definition.name.clone(),
+            kind: self.definition.action.kind(),
+            schedule: self.schedule.as_ref().map(|schedule| schedule.trigger.to_string()),
+            zone,
+            enabled: self.enabled,
+            state,
+            last_started: stats.last_started,
+            last_finished: stats.last_finished,
+            last_outcome: stats.last_outcome,
+            last_success: stats.last_success,
+            last_lateness: stats.last_lateness,
+            warn_after: self.warn_after(default_warn_after),
+            timeout: self.schedule.as_ref().and_then(|schedule| schedule.timeout),
+            expect_success_within: self.expect_success_within,
+            overdue: stats.overdue,
+            next_fire,
+            success_count: stats.success_count,
+            failure_count: stats.failure_count,
+        }
+    }
+}
+
+/// How long after its fire time a cron job may start before the engine is considered stalled
+const CRON_GRACE: chrono::Duration = chrono::Duration::seconds(30);
+
+/// How long the cron engine may take to answer a health check
+const ENGINE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How often `drain` checks for runs still in progress
+const DRAIN_POLL: Duration = Duration::from_millis(50);
+
+/// Settings of the runtime taken from the builder
+#[derive(Default)]
+pub(crate) struct RuntimeOptions {
+    pub(crate) fail_fast: bool,
+    /// History entries kept per task
+    pub(crate) history_size: usize,
+    pub(crate) notifier: Arc<Notifier>,
+    pub(crate) span_layout: SpanLayout,
+    /// Warn when a run starts later than this after its fire time
+    pub(crate) lateness_warning: Option<Duration>,
+    /// `warn_after` of tasks that set none
+    pub(crate) warn_after: Option<Duration>,
+}
+
+/// What the config says a task should look like
+pub(crate) enum DesiredState {
+    Disabled(&'static str),
+    Scheduled(ResolvedSchedule),
+}
+
+/// Shared state of a running scheduler
+///
+/// Owns the scheduled jobs so they can be replaced when the config is reloaded.
+pub(crate) struct SchedulerRuntime {
+    cron_scheduler: JobScheduler,
+    config_loader: Option<ConfigLoader>,
+    tasks: Mutex<Vec<TaskSlot>>,
+    /// File watcher and signal listener tasks
+    background: std::sync::Mutex<Vec<JoinHandle<()>>>,
+    notifier: Arc<Notifier>,
+    /// `warn_after` of tasks that set none
+    warn_after: Option<Duration>,
+    /// Start of the `expect_success_within` window of tasks that never succeeded
+    started_at: DateTime<Utc>,
+}
+
+impl SchedulerRuntime {
+    /// Schedule every enabled task and start the cron scheduler
+    ///
+    /// Every task is resolved and validated before any job is launched, so with
+    /// `fail_fast` a failure aborts the start without leaving tasks running.
+    pub(crate) async fn start(
+        config: Arc<Config>,
+        config_loader: Option<ConfigLoader>,
+        definitions: Vec<TaskDefinition>,
+        options: RuntimeOptions,
+        mut report: StartReport,
+    ) -> Result<(Arc<Self>, StartReport), ConcertoError> {
+        let mut tasks = Vec::with_capacity(definitions.len());
+        let mut pending = Vec::new();
+
+        for definition in definitions {
+            let mut slot = TaskSlot::new(definition, &options);
+            let expect_success_within = slot.definition.resolve_expect_success_within(&config);
+            if let Ok(window) = &expect_success_within {
+                slot.expect_success_within = *window;
+            }
+            let definition = &slot.definition;
+            let prepared = match expect_success_within.and_then(|_| Self::desired_state(definition, &config)) {
+                Ok(DesiredState::Scheduled(schedule)) => {
+                    Self::prepare(&slot.runner, &schedule).map(|job| (schedule, job))
+                }
+                Ok(DesiredState::Disabled(reason)) => {
+                    debug!(
+                        task_name = %definition.name,
+                        task_type = %definition.task_type,
+                        reason = %reason,
+                        "Task disabled, skipping registration"
+                    );
+                    report.disabled.push(DisabledTask {
+                        name: definition.name.clone(),
+                        reason: reason.to_string(),
+                    });
+                    tasks.push(slot);
+                    continue;
+                }
+                Err(e) => Err(e),
+            };
+
+            match prepared {
+                Ok((schedule, job)) => pending.push((tasks.len(), schedule, job)),
+                Err(e) => {
+                    error!(
+                        task_name = %definition.name,
+                        task_type = %definition.task_type,
+                        error = %e,
+                        "Failed to register task"
+                    );
+                    report.failed.push(FailedTask {
+                        name: definition.name.clone(),
+                        error: e,
+                    });
+                }
+            }
+            tasks.push(slot);
+        }
+
+        if options.fail_fast && !report.is_complete() {
+            return Err(ConcertoError::StartFailed(report));
+        }
+
+        let mut runtime = Self {
+            cron_scheduler: JobScheduler::new().await?,
+            config_loader,
+            tasks: Mutex::new(Vec::new()),
+            background: std::sync::Mutex::new(Vec::new()),
+            notifier: options.notifier.clone(),
+            warn_after: options.warn_after,
+            started_at: Utc::now(),
+        };
+
+        for (index, schedule, job) in pending {
+            let slot = &mut tasks[index];
+            info!(
+                task_name = %slot.definition.name,
+                task_type = %slot.definition.task_type,
+                "Registering task"
+            );
+
+            match Self::launch(&runtime.cron_scheduler, &slot.definition, job).await {
+                Ok(job) => {
+                    report.registered.push(RegisteredTask {
+                        name: slot.definition.name.clone(),
+                        schedule: schedule.trigger.to_string(),
+                    });
+                    slot.runner.set_timeout(schedule.timeout);
+                    slot.schedule = Some(schedule);
+                    slot.job = Some(job);
+                    slot.enabled = true;
+                }
+                Err(e) => {
+                    error!(
+                        task_name = %slot.definition.name,
+                        task_type = %slot.definition.task_type,
+                        error = %e,
+                        "Failed to register task"
+                    );
+                    report.failed.push(FailedTask {
+                        name: slot.definition.name.clone(),
+                        error: e,
+                    });
+                    if options.fail_fast {
+                        runtime.unschedule_all(&mut tasks).await;
+                        report.registered.clear();
+                        return Err(ConcertoError::StartFailed(report));
+                    }
+                }
+            }
+        }
+
+        if let Err(e) = runtime.cron_scheduler.start().await {
+            runtime.unschedule_all(&mut tasks).await;
+            return Err(e.into());
+        }
+
+        for slot in &tasks {
+            if let Some(schedule) = &slot.schedule {
+                slot.runner.scheduled(&schedule.trigger.to_string(), None);
+            }
+        }
+        for task in &report.disabled {
+            runtime.notifier.emit(|| SchedulerEvent::TaskDisabled {
+                task: task.name.clone(),
+                reason: task.reason.clone(),
+                at: Utc::now(),
+            });
+        }
+        runtime.notifier.emit(|| SchedulerEvent::SchedulerStarted { at: Utc::now() });
+
+        *runtime.tasks.get_mut() = tasks;
+        Ok((Arc::new(runtime), report))
+    }
+
+    /// Build the job for a resolved schedule without starting it
+    ///
+    /// Everything that can be rejected (cron expression, zone) is checked here,
+    /// so a task being rescheduled is only stopped once its replacement is valid.
+    fn prepare(runner: &Arc<TaskRunner>, schedule: &ResolvedSchedule) -> Result<PreparedJob, ConcertoError> {
+        match &schedule.trigger {
+            Trigger::Cron { expression, zone } => Ok(PreparedJob::Cron(cron_job(expression, zone, runner.clone())?)),
+            Trigger::FixedRate { interval_millis } | Trigger::FixedDelay { interval_millis } => {
+                Ok(PreparedJob::Interval {
+                    runner: runner.clone(),
+                    interval_millis: *interval_millis,
+                    initial_delay_millis: schedule.initial_delay_millis,
+                    is_fixed_delay: matches!(schedule.trigger, Trigger::FixedDelay { .. }),
+                })
+            }
+        }
+    }
+
+    /// Put a prepared job on the cron scheduler or spawn its interval loop
+    async fn launch(
+        cron_scheduler: &JobScheduler,
+        definition: &TaskDefinition,
+        job: PreparedJob,
+    ) -> Result<ScheduledJob, ConcertoError> {
+        match job {
+            PreparedJob::Cron(job) => {
+                let uuid = cron_scheduler.add(job).await?;
+                Ok(ScheduledJob::Cron { uuid, since: Utc::now() })
+            }
+            PreparedJob::Interval { runner, interval_millis, initial_delay_millis, is_fixed_delay } => {
+                // fixed_rate waits one interval after the initial delay, fixed_delay runs right away
+                let first_run_millis = if is_fixed_delay {
+                    initial_delay_millis
+                } else {
+                    initial_delay_millis.saturating_add(interval_millis)
+                };
+                let first_run = Utc::now() + chrono::Duration::milliseconds(first_run_millis as i64);
+                let handle = spawn_interval(runner, interval_millis, initial_delay_millis, is_fixed_delay);
+                info!(
+                    task_name = %definition.name,
+                    task_type = %definition.task_type,
+                    schedule_type = %definition.schedule_type,
+                    "Task registered as tokio::interval task"
+                );
+                Ok(ScheduledJob::Interval { handle, first_run })
+            }
+        }
+    }
+
+    async fn unschedule(&self, job: ScheduledJob) -> Result<(), ConcertoError> {
+        match job {
+            ScheduledJob::Cron { uuid, .. } => self.cron_scheduler.remove(&uuid).await?,
+            ScheduledJob::Interval { handle, .. } => handle.abort(),
+        }
+        Ok(())
+    }
+
+    /// Stop every job launched so far, used when the start is aborted
+    async fn unschedule_all(&self, tasks: &mut [TaskSlot]) {
+        for slot in tasks {
+            if let Some(job) = slot.job.take() {
+                if let Err(e) = self.unschedule(job).await {
+                    warn!(task_name = %slot.definition.name, error = %e, "Failed to stop task");
+                }
+            }
+            slot.schedule = None;
+        }
+    }
+
+    /// Resolve and validate a task like `start` does, without scheduling anything
+    pub(crate) fn dry_run(definition: &TaskDefinition, config: &Config) -> Result<DesiredState, ConcertoError> {
+        definition.resolve_expect_success_within(config)?;
+        let desired = Self::desired_state(definition, config)?;
+        if let DesiredState::Scheduled(schedule) = &desired {
+            let runner = TaskRunner::new(definition.name.clone(), definition.action.clone(), &RuntimeOptions::default());
+            Self::prepare(&runner, schedule)?;
+        }
+        Ok(desired)
+    }
+
+    fn desired_state(definition: &TaskDefinition, config: &Config) -> Result<DesiredState, ConcertoError> {
+        if let Some(reason) = definition.disabled_reason(config)? {
+            return Ok(DesiredState::Disabled(reason));
+        }
+        definition.resolve_schedule(config).map(DesiredState::Scheduled)
+    }
+
+    /// Re-read the config and reschedule, enable or disable tasks whose resolved values changed
+    ///
+    /// Tasks that fail to resolve with the new config keep their previous schedule;
+    /// their errors are returned together once every other task was updated.
+    pub(crate) async fn reload(&self) -> Result<(), ConcertoError> {
+        let loader = self.config_loader.as_ref().ok_or(ConcertoError::ReloadUnavailable)?;
+        let config = loader.load()?;
+
+        info!("Reloading configuration");
+
+        let mut tasks = self.tasks.lock().await;
+        let mut changed = 0;
+        let mut failures = Vec::new();
+
+        for slot in tasks.iter_mut() {
+            let name = slot.definition.name.clone();
+            match slot.definition.resolve_expect_success_within(&config) {
+                Ok(window) => slot.expect_success_within = window,
+                Err(e) => {
+                    error!(
+                        task_name = %name,
+                        error = %e,
+                        "Failed to resolve expect_success_within with reloaded config, keeping previous value"
+                    );
+                    failures.push((name.clone(), e));
+                    continue;
+                }
+            }
+            let desired = match Self::desired_state(&slot.definition, &config) {
+                Ok(desired) => desired,
+                Err(e) => {
+                    error!(
+                        task_name = %name,
+                        error = %e,
+                        "Failed to resolve task with reloaded config, keeping previous schedule"
+                    );
+                    failures.push((name.clone(), e));
+                    continue;
+                }
+            };
+
+            match desired {
+                DesiredState::Disabled(reason) => {
+                    slot.enabled = false;
+                    if let Some(job) = slot.job.take() {
+                        if let Err(e) = self.unschedule(job).await {
+                            failures.push((name.clone(), e));
+                        }
+                        slot.schedule = None;
+                        changed += 1;
+                        slot.runner.disabled(reason);
+                        info!(task_name = %name, reason = %reason, "Task disabled by config reload");
+                    }
+                }
+                DesiredState::Scheduled(schedule) => {
+                    let previous = slot.schedule.clone();
+                    if previous.as_ref() == Some(&schedule) {
+                        continue;
+                    }
+                    if previous.as_ref().is_some_and(|previous| previous.same_job(&schedule)) {
+                        info!(
+                            task_name = %name,
+                            warn_after_ms = schedule.warn_after.map(|limit| limit.as_millis() as u64),
+                            timeout_ms = schedule.timeout.map(|limit| limit.as_millis() as u64),
+                            "Task warn_after or timeout changed by config reload"
+                        );
+                        slot.runner.set_timeout(schedule.timeout);
+                        slot.schedule = Some(schedule);
+                        changed += 1;
+                        continue;
+                    }
+
+                    let prepared = match Self::prepare(&slot.runner, &schedule) {
+                        Ok(prepared) => prepared,
+                        Err(e) => {
+                            error!(
+                                task_name = %name,
+                                error = %e,
+                                "Failed to reschedule task after config reload, keeping previous schedule"
+                            );
+                            failures.push((name.clone(), e));
+                            continue;
+                        }
+                    };
+
+                    if let Some(job) = slot.job.take() {
+                        if let Err(e) = self.unschedule(job).await {
+                            failures.push((name.clone(), e));
+                        }
+                    }
+
+                    match Self::launch(&self.cron_scheduler, &slot.definition, prepared).await {
+                        Ok(job) => {
+                            slot.job = Some(job);
+                            slot.enabled = true;
+                            slot.runner.set_timeout(schedule.timeout);
+                            slot.schedule = Some(schedule.clone());
+                            slot.runner.scheduled(
+                                &schedule.trigger.to_string(),
+                                previous.as_ref().map(|previous| previous.trigger.to_string()).as_deref(),
+                            );
+                            changed += 1;
+                            match previous {
+                                Some(previous) => info!(
+                                    task_name = %name,
+                                    old_schedule = %previous.trigger,
+                                    new_schedule = %schedule.trigger,
+                                    old_initial_delay_ms = previous.initial_delay_millis,
+                                    new_initial_delay_ms = schedule.initial_delay_millis,
+                                    "Task rescheduled by config reload"
+                                ),
+                                None => info!(
+                                    task_name = %name,
+                                    schedule = %schedule.trigger,
+                                    "Task enabled by config reload"
+                                ),
+                            }
+                        }
+                        Err(e) => {
+                            // The previous job is already stopped
+                            slot.schedule = None;
+                            slot.enabled = false;
+                            error!(task_name = %name, error = %e, "Failed to reschedule task after config reload");
+                            failures.push((name.clone(), e));
+                        }
+                    }
+                }
+            }
+        }
+
+        info!(changed_tasks = changed, failed_tasks = failures.len(), "Configuration reloaded");
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ConcertoError::ReloadIncomplete(failures))
+        }
+    }
+
+    /// Poll the config files and reload when one of them changes
+    pub(crate) fn watch_config_files(self: &Arc<Self>, poll_interval: Duration) {
+        let paths = match &self.config_loader {
+            Some(loader) => loader.watched_paths(),
+            None => {
+                warn!("Config watching is enabled but the config was not loaded from files, ignoring");
+                return;
+            }
+        };
+
+        info!(
+            files = ?paths,
+            poll_interval_ms = poll_interval.as_millis() as u64,
+            "Watching config files for changes"
+        );
+
+        let runtime = Arc::downgrade(self);
+        let handle = tokio::spawn(async move {
+            let mut last_seen = file_fingerprints(&paths);
+            let mut ticker = tokio::time::interval(poll_interval);
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                let current = file_fingerprints(&paths);
+                if current == last_seen {
+                    continue;
+                }
+                last_seen = current;
+
+                info!("Config file change detected");
+                if !reload_from(&runtime).await {
+                    break;
+                }
+            }
+        });
+
+        self.background.lock().unwrap_or_else(|e| e.into_inner()).push(handle);
+    }
+
+    /// Every `period`, report runs exceeding their `warn_after` and tasks
+    /// without a success within their `expect_success_within`
+    pub(crate) fn spawn_watchdog(self: &Arc<Self>, period: Duration) {
+        let runtime = Arc::downgrade(self);
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                let Some(runtime) = runtime.upgrade() else {
+                    break;
+                };
+                let now = Utc::now();
+                let tasks = runtime.tasks.lock().await;
+                for slot in tasks.iter() {
+                    if let Some(limit) = slot.warn_after(runtime.warn_after) {
+                        slot.runner.check_stuck(limit);
+                    }
+                    if let Some(window) = slot.expect_success_within {
+                        slot.runner.check_overdue(window, runtime.started_at, now);
+                    }
+                }
+            }
+        });
+
+        self.background.lock().unwrap_or_else(|e| e.into_inner()).push(handle);
+    }
+
+    /// Serve the admin HTTP API until shutdown
+    #[cfg(feature = "admin-http")]
+    pub(crate) fn serve_admin_http(self: &Arc<Self>, listener: crate::admin::AdminListener) {
+        let handle = listener.serve(Arc::downgrade(self));
+        self.background.lock().unwrap_or_else(|e| e.into_inner()).push(handle);
+    }
+
+    /// Serve the control socket until shutdown
+    #[cfg(all(feature = "control-socket", unix))]
+    pub(crate) fn serve_control_socket(self: &Arc<Self>, listener: crate::admin::ControlListener) {
+        let handle = listener.serve(Arc::downgrade(self));
+        self.background.lock().unwrap_or_else(|e| e.into_inner()).push(handle);
+    }
+
+    /// Reload the config whenever the process receives SIGHUP
+    #[cfg(unix)]
+    pub(crate) fn reload_on_sighup(self: &Arc<Self>, hangup: Hangup) {
+        let Hangup(mut hangup) = hangup;
+        let runtime = Arc::downgrade(self);
+        let handle = tokio::spawn(async move {
+            while hangup.recv().await.is_some() {
+                info!("SIGHUP received");
+                if !reload_from(&runtime).await {
+                    break;
+                }
+            }
+        });
+
+        self.background.lock().unwrap_or_else(|e| e.into_inner()).push(handle);
+    }
+
+    /// Reload the config whenever the process receives SIGHUP
+    #[cfg(not(unix))]
+    pub(crate) fn reload_on_sighup(self: &Arc<Self>, _hangup: Hangup) {}
+
+    /// Next `n` fire times of a task, `None` if there is no task with this name
+    ///
+    /// Disabled tasks have no fire times.
+    pub(crate) async fn next_fire_times(&self, name: &str, n: usize) -> Option<Vec<DateTime<Utc>>> {
+        let tasks = self.tasks.lock().await;
+        let slot = tasks.iter().find(|slot| slot.definition.name == name)?;
+        if slot.runner.is_paused() {
+            return Some(Vec::new());
+        }
+        Some(slot.fire_times(Utc::now(), n))
+    }
+
+    pub(crate) async fn task_info(&self, name: &str) -> Option<TaskInfo> {
+        let tasks = self.tasks.lock().await;
+        let slot = tasks.iter().find(|slot| slot.definition.name == name)?;
+        Some(slot.info(Utc::now(), self.warn_after))
+    }
+
+    pub(crate) async fn history(&self, name: &str) -> Option<Vec<TaskExecution>> {
+        let tasks = self.tasks.lock().await;
+        let slot = tasks.iter().find(|slot| slot.definition.name == name)?;
+        Some(slot.runner.history())
+    }
+
+    pub(crate) async fn task_infos(&self) -> Vec<TaskInfo> {
+        let now = Utc::now();
+        self.tasks.lock().await.iter().map(|slot| slot.info(now, self.warn_after)).collect()
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) async fn render_metrics(&self) -> String {
+        let tasks = self.tasks.lock().await;
+        let samples: Vec<_> = tasks.iter().map(|slot| slot.runner.metrics_sample()).collect();
+        super::metrics::render(&samples)
+    }
+
+    /// Check the cron engine, the interval loops and every task
+    pub(crate) async fn health(&self) -> HealthReport {
+        let mut cron_scheduler = self.cron_scheduler.clone();
+        let cron_engine_responds = matches!(
+            tokio::time::timeout(ENGINE_TIMEOUT, cron_scheduler.time_till_next_job()).await,
+            Ok(Ok(_))
+        );
+
+        let now = Utc::now();
+        let (mut missed_fires, mut dead_loops) = (Vec::new(), Vec::new());
+        let (mut failing, mut stuck, mut overdue) = (Vec::new(), Vec::new(), Vec::new());
+        let tasks = self.tasks.lock().await;
+        for slot in tasks.iter() {
+            let name = &slot.definition.name;
+            let stats = slot.runner.stats();
+            match &slot.job {
+                Some(ScheduledJob::Cron { since, .. }) if slot.missed_cron_fire(now, *since) => {
+                    missed_fires.push(name.clone());
+                }
+                Some(ScheduledJob::Interval { handle, .. }) if handle.is_finished() => {
+                    dead_loops.push(name.clone());
+                }
+                _ => {}
+            }
+            let failed = stats.last_outcome.as_ref().is_some_and(|outcome| {
+                matches!(outcome, TaskOutcome::Failed(_) | TaskOutcome::Panicked(_) | TaskOutcome::Timeout(_))
+            });
+            if failed && slot.job.is_some() {
+                failing.push(name.clone());
+            }
+            if stats.stuck {
+                stuck.push(name.clone());
+            }
+            if stats.overdue {
+                overdue.push(name.clone());
+            }
+        }
+
+        HealthReport::new(cron_engine_responds, missed_fires, dead_loops, failing, stuck, overdue)
+    }
+
+    pub(crate) fn subscribe(&self) -> tokio::sync::broadcast::Receiver<SchedulerEvent> {
+        self.notifier.subscribe()
+    }
+
+    /// Run a task once right away, in the background
+    ///
+    /// Runs even while the task is paused or disabled.
+    pub(crate) async fn trigger(&self, name: &str) -> Result<(), ConcertoError> {
+        let tasks = self.tasks.lock().await;
+        let slot = tasks
+            .iter()
+            .find(|slot| slot.definition.name == name)
+            .ok_or_else(|| ConcertoError::UnknownTask(name.to_string()))?;
+        let runner = slot.runner.clone();
+        info!(task_name = %name, "Task triggered manually");
+        tokio::spawn(async move { runner.run(Utc::now()).await });
+        Ok(())
+    }
+
+    /// Replace the schedule of a scheduled task until the next config reload
+    ///
+    /// The first run follows the new schedule without initial delay; `warn_after` and `timeout` are kept.
+    pub(crate) async fn reschedule(&self, name: &str, schedule: &Schedule) -> Result<(), ConcertoError> {
+        let trigger = Trigger::from_schedule(schedule)?;
+        let mut tasks = self.tasks.lock().await;
+        let slot = tasks
+            .iter_mut()
+            .find(|slot| slot.definition.name == name)
+            .ok_or_else(|| ConcertoError::UnknownTask(name.to_string()))?;
+        let previous = slot
+            .schedule
+            .clone()
+            .ok_or_else(|| ConcertoError::TaskNotScheduled(name.to_string()))?;
+
+        let schedule = ResolvedSchedule {
+            trigger,
+            initial_delay_millis: 0,
+            warn_after: previous.warn_after,
+            timeout: previous.timeout,
+        };
+        let prepared = Self::prepare(&slot.runner, &schedule)?;
+        if let Some(job) = slot.job.take() {
+            self.unschedule(job).await?;
+        }
+        slot.schedule = None;
+        slot.enabled = false;
+        slot.job = Some(Self::launch(&self.cron_scheduler, &slot.definition, prepared).await?);
+        slot.enabled = true;
+        slot.runner.scheduled(&schedule.trigger.to_string(), Some(&previous.trigger.to_string()));
+        info!(
+            task_name = %name,
+            old_schedule = %previous.trigger,
+            new_schedule = %schedule.trigger,
+            "Task rescheduled manually"
+        );
+        slot.schedule = Some(schedule);
+        Ok(())
+    }
+
+    /// Pause or resume a task; runs already in progress are not affected
+    pub(crate) async fn set_paused(&self, name: &str, paused: bool) -> Result<(), ConcertoError> {
+        let tasks = self.tasks.lock().await;
+        let slot = tasks
+            .iter()
+            .find(|slot| slot.definition.name == name)
+            .ok_or_else(|| ConcertoError::UnknownTask(name.to_string()))?;
+        if slot.runner.set_paused(paused) != paused {
+            info!(task_name = %name, "{}", if paused { "Task paused" } else { "Task resumed" });
+        }
+        Ok(())
+    }
+
+    /// Pause every task, then wait up to `timeout` for runs in progress to finish
+    ///
+    /// Returns the tasks still running when the timeout expired.
+    pub(crate) async fn drain(&self, timeout: Duration) -> Vec<String> {
+        let runners: Vec<Arc<TaskRunner>> = {
+            let tasks = self.tasks.lock().await;
+            tasks.iter().map(|slot| slot.runner.clone()).collect()
+        };
+        for runner in &runners {
+            runner.set_paused(true);
+        }
+        info!(tasks = runners.len(), "Draining scheduler, all tasks paused");
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let running: Vec<String> = runners
+                .iter()
+                .filter(|runner| runner.stats().running > 0)
+                .map(|runner| runner.name.clone())
+                .collect();
+            if running.is_empty() {
+                info!("Scheduler drained, no task running");
+                return running;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                warn!(running_tasks = ?running, "Drain timed out with tasks still running");
+                return running;
+            }
+            tokio::time::sleep(DRAIN_POLL).await;
+        }
+    }
+
+    /// Stop background watchers, the cron scheduler and all interval tasks
+    pub(crate) async fn shutdown(&self) -> Result<(), ConcertoError> {
+        let background: Vec<_> = self.background.lock().unwrap_or_else(|e| e.into_inner()).drain(..).collect();
+        for handle in &background {
+            handle.abort();
+        }
+        // Wait until the tasks are gone, so listeners are closed and the control socket removed
+        for handle in background {
+            let _ = handle.await;
+        }
+
+        let mut cron_scheduler = self.cron_scheduler.clone();
+        cron_scheduler.shutdown().await?;
+
+        let mut tasks = self.tasks.lock().await;
+        for slot in tasks.iter_mut() {
+            if let Some(ScheduledJob::Interval { handle, .. }) = slot.job.take() {
+                handle.abort();
+            }
+        }
+
+        self.notifier.emit(|| SchedulerEvent::SchedulerStopped { at: Utc::now() });
+        Ok(())
+    }
+}
+
+/// SIGHUP registration, made before any task is scheduled so that a failure
+/// leaves nothing running
+#[cfg(unix)]
+pub(crate) struct Hangup(tokio::signal::unix::Signal);
+
+#[cfg(not(unix))]
+pub(crate) struct Hangup;
+
+impl Hangup {
+    #[cfg(unix)]
+    pub(crate) fn listen() -> Result<Self, ConcertoError> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        signal(SignalKind::hangup()).map(Hangup).map_err(ConcertoError::Signal)
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn listen() -> Result<Self, ConcertoError> {
+        warn!("Reloading on SIGHUP is only supported on Unix, ignoring");
+        Ok(Hangup)
+    }
+}
+
+/// Reload through a weak reference; returns `false` once the scheduler is gone
+async fn reload_from(runtime: &Weak<SchedulerRuntime>) -> bool {
+    let Some(runtime) = runtime.upgrade() else {
+        return false;
+    };
+    if let Err(e) = runtime.reload().await {
+        error!(error = %e, "Config reload failed");
+    }
+    true
+}
+
+/// Modification time and size of each file (`None` if missing)
+fn file_fingerprints(paths: &[PathBuf]) -> Vec<Option<(SystemTime, u64)>> {
+    paths
+        .iter()
+        .map(|path| {
+            let metadata = std::fs::metadata(path).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        })
+        .collect()
+}
+
+/// Create a cron job firing in the given zone (`Local`, `UTC` or an IANA name)
+fn cron_job(expression: &str, zone: &str, runner: Arc<TaskRunner>) -> Result<Job, ConcertoError> {
+    let schedule = Schedule::cron(expression, zone)?;
+    let run = move |_uuid, _lock| {
+        let runner = runner.clone();
+        let now = Utc::now();
+        let scheduled_at = schedule.last_fire_time(now).unwrap_or(now);
+        Box::pin(async move {
+            runner.fire(scheduled_at).await;
+        }) as std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>
+    };
+
+    let job = match Zone::parse(zone)? {
+        Zone::Local => Job::new_async_tz(expression, chrono::Local, run),
+        Zone::Utc => Job::new_async_tz(expression, chrono::Utc, run),
+        Zone::Named(timezone) => Job::new_async_tz(expression, timezone, run),
+    };
+    job.map_err(|e| match e {
+        JobSchedulerError::ParseSchedule => ConcertoError::InvalidCron {
+            expression: expression.to_string(),
+        },
+        e => ConcertoError::Engine(e),
+    })
+}
+
+/// Spawn the loop of a fixed_rate or fixed_delay task
+fn spawn_interval(
+    runner: Arc<TaskRunner>,
+    interval_millis: u64,
+    initial_delay_millis: u64,
+    is_fixed_delay: bool,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        if initial_delay_millis > 0 {
+            tokio::time::sleep(Duration::from_millis(initial_delay_millis)).await;
+        }
+
+        let period = Duration::from_millis(interval_millis);
+
+        if is_fixed_delay {
+            // The next run is due `period` after the previous one completed
+            let mut due = tokio::time::Instant::now();
+            loop {
+                runner.fire(instant_to_utc(due)).await;
+                due = tokio::time::Instant::now() + period;
+                tokio::time::sleep_until(due).await;
+            }
+        }
+
+        let mut interval = tokio::time::interval(period);
+        interval.tick().await;
+
+        loop {
+            let tick = interval.tick().await;
+            let runner = runner.clone();
+            tokio::spawn(async move {
+                runner.fire(instant_to_utc(tick)).await;
+            });
+        }
+    })
+}
+
+/// Wall-clock time of a tokio instant in the past
+fn instant_to_utc(instant: tokio::time::Instant) -> DateTime<Utc> {
+    let elapsed = tokio::time::Instant::now().saturating_duration_since(instant);
+    Utc::now() - chrono::Duration::from_std(elapsed).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::{ScheduledTask, TaskFuture};
+    use crate::HealthStatus;
+
+    fn idle() -> TaskFuture {
+        Box::pin(async { Ok(()) })
+    }
+
+    async fn start(tasks: Vec<ScheduledTask>) -> Arc<SchedulerRuntime> {
+        let definitions = tasks.into_iter().map(TaskDefinition::from_scheduled).collect();
+        let options = RuntimeOptions::default();
+        let (runtime, report) =
+            SchedulerRuntime::start(Arc::new(Config::default()), None, definitions, options, StartReport::default())
+                .await
+                .unwrap();
+        assert!(report.failed.is_empty());
+        runtime
+    }
+
+    #[tokio::test]
+    async fn dead_interval_loop_is_unhealthy() {
+        let runtime = start(vec![
+            ScheduledTask::builder("loop", idle).schedule_type("fixed_rate").schedule_value("1h").build(),
+        ])
+        .await;
+        assert_eq!(runtime.health().await.status, HealthStatus::Healthy);
+
+        {
+            let tasks = runtime.tasks.lock().await;
+            let Some(ScheduledJob::Interval { handle, .. }) = &tasks[0].job else {
+                panic!("expected an interval job");
+            };
+            handle.abort();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let health = runtime.health().await;
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+        assert!(!health.is_alive());
+        assert!(health.cron_engine_alive);
+        assert_eq!(health.dead_loops, ["loop"]);
+        runtime.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn missed_cron_fire_is_unhealthy() {
+        let runtime = start(vec![ScheduledTask::builder("hourly", idle).schedule_value("0 0 * * * *").build()]).await;
+        assert_eq!(runtime.health().await.status, HealthStatus::Healthy);
+
+        // Pretend the job was added two hours ago: the last full hour passed without a fire
+        {
+            let mut tasks = runtime.tasks.lock().await;
+            let Some(ScheduledJob::Cron { since, .. }) = &mut tasks[0].job else {
+                panic!("expected a cron job");
+            };
+            *since = Utc::now() - chrono::Duration::hours(2);
+        }
+
+        let health = runtime.health().await;
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+        assert!(!health.cron_engine_alive);
+        assert_eq!(health.missed_fires, ["hourly"]);
+        runtime.shutdown().await.unwrap();
+    }
+}
@@ -1,13 +1,28 @@
+use super::definition::TaskDefinition;
 use super::handle::SchedulerHandle;
 use super::instance::RegisteredInstance;
-use crate::config::resolve_config_value;
+use super::report::{DisabledTask, StartReport, TaskValidation, ValidationOutcome, ValidationReport};
+use super::event::SchedulerEvent;
+use super::listener::{Notifier, TaskListener};
+use super::runtime::{DesiredState, Hangup, RuntimeOptions, SchedulerRuntime};
+use super::span::SpanLayout;
+#[cfg(feature = "admin-http")]
+use crate::admin::{AdminHttp, AdminListener};
+#[cfg(all(feature = "control-socket", unix))]
+use crate::admin::ControlListener;
+use crate::config::ConfigLoader;
+use crate::error::ConcertoError;
+use crate::profile::profiles_match;
 use crate::runnable::RunnableTask;
-use crate::task::{ScheduledTask, ScheduledMethodMetadata};
-use crate::time_unit::TimeUnit;
+use crate::task::{ScheduledTask, TaskMetadata};
 use config::Config;
 use std::sync::Arc;
-use tokio_cron_scheduler::{Job, JobScheduler};
-use tracing::{debug, error, info, warn};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+/// How often tasks are checked against their `warn_after` and `expect_success_within`
+const WATCHDOG_PERIOD: Duration = Duration::from_secs(1);
 
 /// Configured scheduler ready to start
 /// This struct holds all configuration and tasks but hasn't started yet
@@ -16,127 +31,149 @@ pub struct Scheduler {
     pub(crate) runnable_tasks: Vec<RunnableTask>,
     pub(crate) scheduled_tasks: Vec<ScheduledTask>,
     pub(crate) registered_instances: Vec<RegisteredInstance>,
+    pub(crate) task_metadata: Vec<TaskMetadata>,
+    pub(crate) active_profiles: Vec<String>,
+    pub(crate) config_loader: Option<ConfigLoader>,
+    pub(crate) watch_config: Option<Duration>,
+    pub(crate) reload_on_sighup: bool,
+    pub(crate) fail_fast: bool,
+    pub(crate) history_size: usize,
+    pub(crate) listeners: Vec<Arc<dyn TaskListener>>,
+    pub(crate) events: broadcast::Sender<SchedulerEvent>,
+    pub(crate) span_layout: SpanLayout,
+    pub(crate) lateness_warning: Option<Duration>,
+    pub(crate) warn_after: Option<Duration>,
+    #[cfg(feature = "admin-http")]
+    pub(crate) admin_http: Option<AdminHttp>,
+    #[cfg(all(feature = "control-socket", unix))]
+    pub(crate) control_socket: Option<std::path::PathBuf>,
 }
 
 impl Scheduler {
-    /// Parse and resolve time unit configuration
-    fn parse_time_unit(
-        time_unit_str: &str,
-    ) -> TimeUnit {
-        time_unit_str.parse().unwrap_or_else(|_| {
-            warn!(
-                time_unit = %time_unit_str,
-                "Invalid time_unit, using milliseconds as default"
-            );
-            TimeUnit::Milliseconds
-        })
+    /// Metadata of all tasks that passed the builder's tag filters
+    pub fn tasks(&self) -> &[TaskMetadata] {
+        &self.task_metadata
     }
 
-    /// Parse and resolve initial delay in milliseconds
-    fn parse_initial_delay(
-        initial_delay: &str,
-        time_unit: TimeUnit,
-    ) -> u64 {
-        if let Some((value, parsed_unit)) = TimeUnit::parse_duration(initial_delay) {
-            parsed_unit.to_millis(value)
-        } else {
-            let initial_delay_value: u64 = initial_delay.parse().unwrap_or_else(|_| {
-                warn!(
-                    initial_delay = %initial_delay,
-                    "Invalid initial_delay value, using 0 as default"
-                );
-                0
-            });
-            time_unit.to_millis(initial_delay_value)
-        }
+    /// Profiles active for this scheduler
+    pub fn active_profiles(&self) -> &[String] {
+        &self.active_profiles
     }
 
-    /// Parse and resolve zone display string
-    fn parse_zone_display(zone_str: &str) -> String {
-        if zone_str.to_lowercase() == "local" {
-            "Local".to_string()
-        } else {
-            zone_str.to_string()
-        }
+    /// Receive the scheduler's events, including those emitted by `start`
+    ///
+    /// Same stream as [`SchedulerHandle::subscribe`], but subscribing before
+    /// `start` also delivers `TaskRegistered`, `TaskDisabled` and `SchedulerStarted`.
+    pub fn subscribe(&self) -> broadcast::Receiver<SchedulerEvent> {
+        self.events.subscribe()
     }
 
-    /// Parse interval value and return (value, time_unit, millis)
-    fn parse_interval(
-        interval_str: &str,
-        default_time_unit: TimeUnit,
-    ) -> Result<(u64, TimeUnit, u64), Box<dyn std::error::Error>> {
-        let (interval_value, effective_time_unit) =
-            if let Some((value, parsed_unit)) = TimeUnit::parse_duration(interval_str) {
-                debug!(
-                    interval = %interval_str,
-                    value = %value,
-                    time_unit = ?parsed_unit,
-                    "Parsed interval shorthand"
-                );
-                (value, parsed_unit)
-            } else {
-                let value = interval_str
-                    .parse::<u64>()
-                    .map_err(|_| format!("Invalid interval value: {}", interval_str))?;
-                (value, default_time_unit)
+    /// Check every task against the config without starting anything
+    ///
+    /// Resolves every placeholder, parses every interval, cron expression and
+    /// zone, and records where each value came from. Meant for CI and deploy
+    /// pipelines, to fail on a broken config instead of logging errors at start.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use concerto_runtime::SchedulerBuilder;
+    ///
+    /// let scheduler = SchedulerBuilder::with_yaml("config/application.yaml")
+    ///     .active_profiles(["prod"])
+    ///     .build();
+    ///
+    /// let report = scheduler.validate();
+    /// println!("{}", report);
+    /// if !report.is_valid() {
+    ///     std::process::exit(1);
+    /// }
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        let mut disabled = Vec::new();
+        let definitions = self.task_definitions(&mut disabled);
+
+        let mut report = ValidationReport::default();
+        report.tasks.extend(disabled.into_iter().map(|task| TaskValidation {
+            name: task.name,
+            outcome: ValidationOutcome::Disabled { reason: task.reason },
+            values: Vec::new(),
+        }));
+
+        for definition in &definitions {
+            let outcome = match SchedulerRuntime::dry_run(definition, &self.config) {
+                Ok(DesiredState::Scheduled(schedule)) => ValidationOutcome::Valid {
+                    schedule: schedule.trigger.to_string(),
+                },
+                Ok(DesiredState::Disabled(reason)) => ValidationOutcome::Disabled {
+                    reason: reason.to_string(),
+                },
+                Err(e) => ValidationOutcome::Invalid(e),
             };
-
-        let interval_millis = effective_time_unit.to_millis(interval_value);
-        Ok((interval_value, effective_time_unit, interval_millis))
-    }
-
-    /// Print cron task information
-    fn print_cron_info(
-        cron_expr: &str,
-        zone_display: &str,
-        initial_delay_millis: u64,
-        time_unit_str: &str,
-    ) {
-        debug!(
-            task_type = "cron",
-            cron_expression = %cron_expr,
-            zone = %zone_display,
-            initial_delay_ms = %initial_delay_millis,
-            "Cron task configuration"
-        );
-
-        if time_unit_str.to_lowercase() != "milliseconds" {
-            warn!(
-                time_unit = %time_unit_str,
-                "time_unit parameter is ignored for cron expressions (uses absolute calendar-based time)"
-            );
+            report.tasks.push(TaskValidation {
+                name: definition.name.clone(),
+                outcome,
+                values: definition.resolved_values(&self.config),
+            });
         }
-    }
 
-    /// Print interval task information
-    fn print_interval_info(
-        schedule_type: &str,
-        interval_value: u64,
-        effective_time_unit: TimeUnit,
-        interval_millis: u64,
-        initial_delay_millis: u64,
-        zone_str: &str,
-    ) {
-        debug!(
-            task_type = %schedule_type,
-            interval_value = %interval_value,
-            time_unit = ?effective_time_unit,
-            interval_ms = %interval_millis,
-            initial_delay_ms = %initial_delay_millis,
-            "Interval-based task configuration"
+        info!(
+            checked_tasks = report.tasks.len(),
+            invalid_tasks = report.errors().count(),
+            "Validated scheduler configuration"
         );
+        report
+    }
 
-        if zone_str.to_lowercase() != "local" {
-            warn!(
-                zone = %zone_str,
-                "zone parameter is ignored for interval-based tasks (fixed_rate/fixed_delay use local system time)"
+    /// Definitions of all tasks whose profiles are active
+    ///
+    /// Runnable tasks, auto-discovered `#[scheduled]` functions, then methods of
+    /// registered instances. Tasks skipped because of their profiles are added to `disabled`.
+    fn task_definitions(&self, disabled: &mut Vec<DisabledTask>) -> Vec<TaskDefinition> {
+        let mut definitions: Vec<TaskDefinition> = Vec::with_capacity(self.task_metadata.len());
+        definitions.extend(self.runnable_tasks.iter().cloned().map(TaskDefinition::from_runnable));
+        definitions.extend(self.scheduled_tasks.iter().cloned().map(TaskDefinition::from_scheduled));
+        for registered_instance in &self.registered_instances {
+            definitions.extend(
+                registered_instance
+                    .methods
+                    .iter()
+                    .map(|method| TaskDefinition::from_method(registered_instance, method)),
             );
         }
+
+        definitions.retain(|definition| {
+            let active = profiles_match(definition.profiles, &self.active_profiles);
+            if !active {
+                debug!(
+                    task_name = %definition.name,
+                    task_type = %definition.task_type,
+                    profiles = ?definition.profiles,
+                    "Task profile not active, skipping registration"
+                );
+                disabled.push(DisabledTask {
+                    name: definition.name.clone(),
+                    reason: "profile not active".to_string(),
+                });
+            }
+            active
+        });
+        definitions
     }
 
     /// Start the scheduler with all registered tasks
     /// Returns a SchedulerHandle that can be used to shutdown the scheduler
-    pub async fn start(self) -> Result<SchedulerHandle, Box<dyn std::error::Error>> {
+    ///
+    /// Tasks that fail to register are logged and skipped; the outcome for every
+    /// task is available from [`SchedulerHandle::start_report`].
+    ///
+    /// # Errors
+    ///
+    /// With `fail_fast(true)`, returns [`ConcertoError::StartFailed`] if any task
+    /// fails to register. Also fails with [`ConcertoError::Signal`] if the SIGHUP
+    /// handler of `reload_on_sighup` cannot be installed, or [`ConcertoError::Listen`]
+    /// if an admin address is unavailable. No task is left running in any of these cases.
+    pub async fn start(self) -> Result<SchedulerHandle, ConcertoError> {
         let total_tasks = self.runnable_tasks.len() + self.scheduled_tasks.len();
         let total_method_tasks: usize = self.registered_instances.iter()
             .map(|inst| inst.methods.len())
@@ -146,429 +183,89 @@ impl Scheduler {
             total_tasks = total_tasks + total_method_tasks,
             direct_tasks = total_tasks,
             method_tasks = total_method_tasks,
+            active_profiles = ?self.active_profiles,
             "Starting scheduler"
         );
 
-        let mut scheduler = JobScheduler::new().await?;
-        let mut interval_handles = Vec::new();
-
-        // Register runnable tasks (from Runnable trait implementations)
-        for task in self.runnable_tasks {
-            let enabled = resolve_config_value(task.enabled, &self.config)?;
-            if enabled.to_lowercase() == "false" {
-                debug!(
-                    task_name = %task.name,
-                    task_type = "Runnable",
-                    "Task disabled, skipping registration"
-                );
-                continue;
-            }
-
-            let task_name = task.name;
-            if let Err(e) = Self::register_runnable_task(
-                &mut scheduler,
-                &mut interval_handles,
-                &self.config,
-                task,
-            )
-            .await
-            {
-                error!(
-                    task_name = %task_name,
-                    error = %e,
-                    "Failed to register runnable task"
-                );
-            }
-        }
-
-        // Register scheduled tasks (auto-discovered from #[scheduled] functions)
-        for task in self.scheduled_tasks {
-            let enabled = resolve_config_value(task.enabled, &self.config)?;
-            if enabled.to_lowercase() == "false" {
-                debug!(
-                    task_name = %task.name,
-                    task_type = "Scheduled",
-                    "Task disabled, skipping registration"
-                );
-                continue;
-            }
+        let mut report = StartReport::default();
+        let definitions = self.task_definitions(&mut report.disabled);
+        let watchdog = self.warn_after.is_some()
+            || definitions.iter().any(|definition| {
+                !definition.warn_after.is_empty() || !definition.expect_success_within.is_empty()
+            });
 
-            let task_name = task.name;
-            if let Err(e) = Self::register_scheduled_task(
-                &mut scheduler,
-                &mut interval_handles,
-                &self.config,
-                task,
-            )
-            .await
-            {
-                error!(
-                    task_name = %task_name,
-                    error = %e,
-                    "Failed to register scheduled task"
-                );
-            }
+        // Listeners are set up before any task is scheduled, so failing here leaves nothing running
+        #[cfg(feature = "admin-http")]
+        let admin_listener = match self.admin_http {
+            Some(admin) => Some(AdminListener::bind(admin).await?),
+            None => None,
+        };
+        #[cfg(all(feature = "control-socket", unix))]
+        let control_listener = match self.control_socket {
+            Some(path) => Some(ControlListener::bind(path).await?),
+            None => None,
+        };
+        let hangup = if self.reload_on_sighup { Some(Hangup::listen()?) } else { None };
+
+        // Reloads keep the profiles the scheduler started with
+        let config_loader = self
+            .config_loader
+            .map(|loader| loader.profiles(self.active_profiles.clone()));
+
+        let options = RuntimeOptions {
+            fail_fast: self.fail_fast,
+            history_size: self.history_size,
+            notifier: Arc::new(Notifier::new(self.listeners, self.events)),
+            span_layout: self.span_layout,
+            lateness_warning: self.lateness_warning,
+            warn_after: self.warn_after,
+        };
+        let (runtime, start_report) =
+            SchedulerRuntime::start(self.config, config_loader, definitions, options, report).await?;
+
+        if let Some(poll_interval) = self.watch_config {
+            runtime.watch_config_files(poll_interval);
         }
-
-        // Register method tasks from registered instances
-        for registered_instance in self.registered_instances {
-            for method_meta in &registered_instance.methods {
-                let enabled = resolve_config_value(method_meta.enabled, &self.config)?;
-                if enabled.to_lowercase() == "false" {
-                    debug!(
-                        type_name = %registered_instance.type_name,
-                        method_name = %method_meta.method_name,
-                        task_type = "Method",
-                        "Task disabled, skipping registration"
-                    );
-                    continue;
-                }
-
-                let task_name = format!("{}::{}", registered_instance.type_name, method_meta.method_name);
-                if let Err(e) = Self::register_method_task(
-                    &mut scheduler,
-                    &mut interval_handles,
-                    &self.config,
-                    &registered_instance,
-                    *method_meta,
-                    &task_name,
-                )
-                .await
-                {
-                    error!(
-                        task_name = %task_name,
-                        error = %e,
-                        "Failed to register method task"
-                    );
-                }
-            }
+        if let Some(hangup) = hangup {
+            runtime.reload_on_sighup(hangup);
         }
-
-        scheduler.start().await?;
-        info!("Scheduler started successfully");
-
-        Ok(SchedulerHandle {
-            cron_scheduler: scheduler,
-            interval_handles,
-        })
-    }
-
-    /// Register a runnable task instance
-    async fn register_runnable_task(
-        scheduler: &mut JobScheduler,
-        interval_handles: &mut Vec<tokio::task::JoinHandle<()>>,
-        config: &Arc<Config>,
-        task: RunnableTask,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        info!(
-            task_name = %task.name,
-            task_type = "Runnable",
-            "Registering task"
-        );
-
-        // Parse configuration values
-        let time_unit_str = resolve_config_value(task.time_unit, config)?;
-        let time_unit = Self::parse_time_unit(&time_unit_str);
-
-        let initial_delay = resolve_config_value(task.initial_delay, config)?;
-        let initial_delay_millis = Self::parse_initial_delay(&initial_delay, time_unit);
-
-        let zone_str = resolve_config_value(task.zone, config)?;
-        let zone_display = Self::parse_zone_display(&zone_str);
-
-        match task.schedule_type {
-            "cron" => {
-                let cron_expr = resolve_config_value(task.schedule_value, config)?;
-                Self::print_cron_info(&cron_expr, &zone_display, initial_delay_millis, &time_unit_str);
-
-                let instance = task.instance.clone();
-
-                let job = Job::new_async(cron_expr.as_str(), move |_uuid, _lock| {
-                    let instance = instance.clone();
-                    Box::pin(async move {
-                        tokio::task::spawn_blocking(move || {
-                            instance.run();
-                        }).await.ok();
-                    })
-                })?;
-
-                scheduler.add(job).await?;
-            }
-            "fixed_rate" | "fixed_delay" => {
-                let interval_str = resolve_config_value(task.schedule_value, config)?;
-                let (interval_value, effective_time_unit, interval_millis) = 
-                    Self::parse_interval(&interval_str, time_unit)?;
-
-                Self::print_interval_info(
-                    task.schedule_type,
-                    interval_value,
-                    effective_time_unit,
-                    interval_millis,
-                    initial_delay_millis,
-                    &zone_str,
-                );
-
-                let instance = task.instance.clone();
-                let is_fixed_delay = task.schedule_type == "fixed_delay";
-
-                let handle = tokio::spawn(async move {
-                    if initial_delay_millis > 0 {
-                        tokio::time::sleep(std::time::Duration::from_millis(
-                            initial_delay_millis,
-                        ))
-                        .await;
-                    }
-
-                    let mut interval = tokio::time::interval(std::time::Duration::from_millis(
-                        interval_millis,
-                    ));
-
-                    interval.tick().await;
-
-                    loop {
-                        if is_fixed_delay {
-                            let instance_clone = instance.clone();
-                            tokio::task::spawn_blocking(move || {
-                                instance_clone.run();
-                            }).await.ok();
-                            interval.tick().await;
-                        } else {
-                            interval.tick().await;
-                            let instance_clone = instance.clone();
-                            tokio::spawn(async move {
-                                tokio::task::spawn_blocking(move || {
-                                    instance_clone.run();
-                                }).await.ok();
-                            });
-                        }
-                    }
-                });
-
-                interval_handles.push(handle);
-                info!(
-                    task_name = %task.name,
-                    task_type = "Runnable",
-                    schedule_type = %task.schedule_type,
-                    "Task registered as tokio::interval task"
-                );
-            }
-            _ => {
-                return Err(format!("Unknown schedule type: {}", task.schedule_type).into());
-            }
+        if watchdog {
+            runtime.spawn_watchdog(WATCHDOG_PERIOD);
         }
-
-        Ok(())
-    }
-
-    /// Register a scheduled task (from #[scheduled] macro)
-    async fn register_scheduled_task(
-        scheduler: &mut JobScheduler,
-        interval_handles: &mut Vec<tokio::task::JoinHandle<()>>,
-        config: &Arc<Config>,
-        task: ScheduledTask,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        info!(
-            task_name = %task.name,
-            task_type = "Scheduled",
-            "Registering task"
-        );
-
-        // Parse configuration values
-        let time_unit_str = resolve_config_value(task.time_unit, config)?;
-        let time_unit = Self::parse_time_unit(&time_unit_str);
-
-        let initial_delay = resolve_config_value(task.initial_delay, config)?;
-        let initial_delay_millis = Self::parse_initial_delay(&initial_delay, time_unit);
-
-        let zone_str = resolve_config_value(task.zone, config)?;
-        let zone_display = Self::parse_zone_display(&zone_str);
-
-        match task.schedule_type {
-            "cron" => {
-                let cron_expr = resolve_config_value(task.schedule_value, config)?;
-                Self::print_cron_info(&cron_expr, &zone_display, initial_delay_millis, &time_unit_str);
-
-                let handler = task.handler;
-
-                let job = Job::new_async(cron_expr.as_str(), move |_uuid, _lock| {
-                    Box::pin(async move {
-                        handler();
-                    })
-                })?;
-
-                scheduler.add(job).await?;
-            }
-            "fixed_rate" | "fixed_delay" => {
-                let interval_str = resolve_config_value(task.schedule_value, config)?;
-                let (interval_value, effective_time_unit, interval_millis) = 
-                    Self::parse_interval(&interval_str, time_unit)?;
-
-                Self::print_interval_info(
-                    task.schedule_type,
-                    interval_value,
-                    effective_time_unit,
-                    interval_millis,
-                    initial_delay_millis,
-                    &zone_str,
-                );
-
-                let handler = task.handler;
-                let is_fixed_delay = task.schedule_type == "fixed_delay";
-
-                let handle = tokio::spawn(async move {
-                    if initial_delay_millis > 0 {
-                        tokio::time::sleep(std::time::Duration::from_millis(
-                            initial_delay_millis,
-                        ))
-                        .await;
-                    }
-
-                    let mut interval = tokio::time::interval(std::time::Duration::from_millis(
-                        interval_millis,
-                    ));
-
-                    interval.tick().await;
-
-                    loop {
-                        if is_fixed_delay {
-                            handler();
-                            interval.tick().await;
-                        } else {
-                            interval.tick().await;
-                            let handler_clone = handler;
-                            tokio::spawn(async move {
-                                handler_clone();
-                            });
-                        }
-                    }
-                });
-
-                interval_handles.push(handle);
-                info!(
-                    task_name = %task.name,
-                    task_type = "Scheduled",
-                    schedule_type = %task.schedule_type,
-                    "Task registered as tokio::interval task"
-                );
-            }
-            _ => {
-                return Err(format!("Unknown schedule type: {}", task.schedule_type).into());
-            }
+        #[cfg(feature = "admin-http")]
+        let admin_http_addr = admin_listener.and_then(|listener| {
+            let address = listener.local_addr();
+            runtime.serve_admin_http(listener);
+            info!(address = ?address, "Admin HTTP server listening");
+            address
+        });
+        #[cfg(all(feature = "control-socket", unix))]
+        if let Some(listener) = control_listener {
+            info!(path = %listener.path().display(), "Control socket listening");
+            runtime.serve_control_socket(listener);
         }
 
-        Ok(())
-    }
-
-    /// Register a method task from a registered instance
-    async fn register_method_task(
-        scheduler: &mut JobScheduler,
-        interval_handles: &mut Vec<tokio::task::JoinHandle<()>>,
-        config: &Arc<Config>,
-        registered_instance: &RegisteredInstance,
-        method_meta: ScheduledMethodMetadata,
-        task_name: &str,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        info!(
-            task_name = %task_name,
-            task_type = "Method",
-            "Registering task"
-        );
-
-        // Parse configuration values
-        let time_unit_str = resolve_config_value(method_meta.time_unit, config)?;
-        let time_unit = Self::parse_time_unit(&time_unit_str);
-
-        let initial_delay = resolve_config_value(method_meta.initial_delay, config)?;
-        let initial_delay_millis = Self::parse_initial_delay(&initial_delay, time_unit);
-
-        let zone_str = resolve_config_value(method_meta.zone, config)?;
-        let zone_display = Self::parse_zone_display(&zone_str);
-
-        match method_meta.schedule_type {
-            "cron" => {
-                let cron_expr = resolve_config_value(method_meta.schedule_value, config)?;
-                Self::print_cron_info(&cron_expr, &zone_display, initial_delay_millis, &time_unit_str);
-
-                let instance = registered_instance.instance.clone();
-                let caller = registered_instance.caller.clone();
-                let method_name = method_meta.method_name.to_string();
-
-                let job = Job::new_async(cron_expr.as_str(), move |_uuid, _lock| {
-                    let instance = instance.clone();
-                    let caller = caller.clone();
-                    let method_name = method_name.clone();
-                    Box::pin(async move {
-                        let future = caller(instance.as_ref(), &method_name);
-                        future.await;
-                    })
-                })?;
-
-                scheduler.add(job).await?;
-            }
-            "fixed_rate" | "fixed_delay" => {
-                let interval_str = resolve_config_value(method_meta.schedule_value, config)?;
-                let (interval_value, effective_time_unit, interval_millis) = 
-                    Self::parse_interval(&interval_str, time_unit)?;
-
-                Self::print_interval_info(
-                    method_meta.schedule_type,
-                    interval_value,
-                    effective_time_unit,
-                    interval_millis,
-                    initial_delay_millis,
-                    &zone_str,
-                );
-
-                let instance = registered_instance.instance.clone();
-                let caller = registered_instance.caller.clone();
-                let method_name = method_meta.method_name.to_string();
-                let is_fixed_delay = method_meta.schedule_type == "fixed_delay";
-
-                let handle = tokio::spawn(async move {
-                    if initial_delay_millis > 0 {
-                        tokio::time::sleep(std::time::Duration::from_millis(
-                            initial_delay_millis,
-                        ))
-                        .await;
-                    }
-
-                    let mut interval = tokio::time::interval(std::time::Duration::from_millis(
-                        interval_millis,
-                    ));
-
-                    interval.tick().await;
-
-                    loop {
-                        if is_fixed_delay {
-                            let future = caller(instance.as_ref(), &method_name);
-                            future.await;
-                            interval.tick().await;
-                        } else {
-                            interval.tick().await;
-                            let instance_clone = instance.clone();
-                            let caller_clone = caller.clone();
-                            let method_name_clone = method_name.clone();
-                            tokio::spawn(async move {
-                                let future = caller_clone(instance_clone.as_ref(), &method_name_clone);
-                                future.await;
-                            });
-                        }
-                    }
-                });
-
-                interval_handles.push(handle);
-                info!(
-                    task_name = %task_name,
-                    task_type = "Method",
-                    schedule_type = %method_meta.schedule_type,
-                    "Task registered as tokio::interval task"
-                );
-            }
-            _ => {
-                return Err(format!("Unknown schedule type: {}", method_meta.schedule_type).into());
-            }
+        if start_report.is_complete() {
+            info!(
+                registered_tasks = start_report.registered.len(),
+                disabled_tasks = start_report.disabled.len(),
+                "Scheduler started successfully"
+            );
+        } else {
+            warn!(
+                registered_tasks = start_report.registered.len(),
+                disabled_tasks = start_report.disabled.len(),
+                failed_tasks = start_report.failed.len(),
+                "Scheduler started, but some tasks could not be registered"
+            );
         }
 
-        Ok(())
+        Ok(SchedulerHandle {
+            runtime,
+            task_metadata: self.task_metadata,
+            start_report,
+            #[cfg(feature = "admin-http")]
+            admin_http_addr,
+        })
     }
 }
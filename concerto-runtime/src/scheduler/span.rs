@@ -0,0 +1,195 @@
+use super::info::{TaskKind, TaskOutcome};
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use tracing::field::Empty;
+use tracing::{info_span, Span};
+use uuid::Uuid;
+
+/// Field names of the `concerto.task` span wrapped around every run
+///
+/// | `Default`      | `OpenTelemetry`           |
+/// |----------------|---------------------------|
+/// | `name`         | `concerto.task.name`      |
+/// | `kind`         | `concerto.task.kind`      |
+/// | `run_id`       | `concerto.run_id`         |
+/// | `scheduled_at` | `concerto.scheduled_at`   |
+/// | `duration_ms`  | `concerto.duration_ms`    |
+/// | `outcome`      | `concerto.outcome`        |
+/// |                | `otel.name`, `otel.kind`, `otel.status_code`, `otel.status_message` |
+///
+/// `OpenTelemetry` follows the attribute conventions of `tracing-opentelemetry`, so the
+/// span is exported named after the task, as an internal span with an error status on failure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpanLayout {
+    #[default]
+    Default,
+    OpenTelemetry,
+}
+
+/// Create the span of a single run
+pub(crate) fn task_span(layout: SpanLayout, name: &str, kind: TaskKind, scheduled_at: DateTime<Utc>) -> Span {
+    let run_id = Uuid::new_v4();
+    match layout {
+        SpanLayout::Default => info_span!(
+            "concerto.task",
+            name = %name,
+            kind = %kind,
+            run_id = %run_id,
+            scheduled_at = %scheduled_at.to_rfc3339(),
+            duration_ms = Empty,
+            outcome = Empty,
+        ),
+        SpanLayout::OpenTelemetry => info_span!(
+            "concerto.task",
+            otel.name = %name,
+            otel.kind = "internal",
+            otel.status_code = Empty,
+            otel.status_message = Empty,
+            concerto.task.name = %name,
+            concerto.task.kind = %kind,
+            concerto.run_id = %run_id,
+            concerto.scheduled_at = %scheduled_at.to_rfc3339(),
+            concerto.duration_ms = Empty,
+            concerto.outcome = Empty,
+        ),
+    }
+}
+
+/// Record duration and outcome before the span closes
+pub(crate) fn record_finish(span: &Span, layout: SpanLayout, duration: Duration, outcome: &TaskOutcome) {
+    let duration_ms = duration.as_secs_f64() * 1000.0;
+    let outcome_name = outcome.label();
+    match layout {
+        SpanLayout::Default => {
+            span.record("duration_ms", duration_ms);
+            span.record("outcome", outcome_name);
+        }
+        SpanLayout::OpenTelemetry => {
+            span.record("concerto.duration_ms", duration_ms);
+            span.record("concerto.outcome", outcome_name);
+            match outcome {
+                TaskOutcome::Failed(message) | TaskOutcome::Panicked(message) => {
+                    span.record("otel.status_code", "ERROR");
+                    span.record("otel.status_message", message.as_str());
+                }
+                TaskOutcome::Timeout(_) => {
+                    span.record("otel.status_code", "ERROR");
+                    span.record("otel.status_message", outcome.to_string().as_str());
+                }
+                _ => {
+                    span.record("otel.status_code", "OK");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    /// Declared field names and recorded values of a span
+    type Fields = (Vec<String>, BTreeMap<String, String>);
+
+    /// Fields of the last span created
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Fields>>);
+
+    impl Visit for Capture {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.lock().unwrap().1.insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.lock().unwrap().1.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for Capture {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            let names = attrs.metadata().fields().iter().map(|field| field.name().to_string()).collect();
+            *self.0.lock().unwrap() = (names, BTreeMap::new());
+            attrs.record(&mut self.clone());
+        }
+
+        fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+            values.record(&mut self.clone());
+        }
+    }
+
+    /// Run a task span through start and finish, returning its field names and values
+    fn finished_span(layout: SpanLayout, outcome: TaskOutcome) -> Fields {
+        let capture = Capture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let scheduled_at = DateTime::parse_from_rfc3339("2025-06-01T09:00:00Z").unwrap().to_utc();
+            let span = task_span(layout, "sync_users", TaskKind::Method, scheduled_at);
+            assert_eq!(span.metadata().map(|metadata| metadata.name()), Some("concerto.task"));
+            record_finish(&span, layout, Duration::from_millis(1500), &outcome);
+        });
+        let captured = capture.0.lock().unwrap();
+        captured.clone()
+    }
+
+    #[test]
+    fn default_layout_fields() {
+        let (names, values) = finished_span(SpanLayout::Default, TaskOutcome::Failed("disk full".to_string()));
+        assert_eq!(names, ["name", "kind", "run_id", "scheduled_at", "duration_ms", "outcome"]);
+        assert_eq!(values["name"], "sync_users");
+        assert_eq!(values["kind"], "method");
+        assert!(Uuid::parse_str(&values["run_id"]).is_ok(), "run_id {}", values["run_id"]);
+        assert_eq!(values["scheduled_at"], "2025-06-01T09:00:00+00:00");
+        assert_eq!(values["duration_ms"], "1500.0");
+        assert_eq!(values["outcome"], "failed");
+    }
+
+    #[test]
+    fn opentelemetry_layout_fields() {
+        let (names, values) = finished_span(SpanLayout::OpenTelemetry, TaskOutcome::Failed("disk full".to_string()));
+        assert_eq!(
+            names,
+            [
+                "otel.name",
+                "otel.kind",
+                "otel.status_code",
+                "otel.status_message",
+                "concerto.task.name",
+                "concerto.task.kind",
+                "concerto.run_id",
+                "concerto.scheduled_at",
+                "concerto.duration_ms",
+                "concerto.outcome",
+            ]
+        );
+        assert_eq!(values["otel.name"], "sync_users");
+        assert_eq!(values["otel.kind"], "internal");
+        assert_eq!(values["otel.status_code"], "ERROR");
+        assert_eq!(values["otel.status_message"], "disk full");
+        assert_eq!(values["concerto.task.name"], "sync_users");
+        assert_eq!(values["concerto.task.kind"], "method");
+        assert!(Uuid::parse_str(&values["concerto.run_id"]).is_ok());
+        assert_eq!(values["concerto.scheduled_at"], "2025-06-01T09:00:00+00:00");
+        assert_eq!(values["concerto.duration_ms"], "1500.0");
+        assert_eq!(values["concerto.outcome"], "failed");
+    }
+
+    #[test]
+    fn opentelemetry_status_per_outcome() {
+        let status = |outcome| {
+            let (_, values) = finished_span(SpanLayout::OpenTelemetry, outcome);
+            (values["otel.status_code"].clone(), values.get("otel.status_message").cloned())
+        };
+        assert_eq!(status(TaskOutcome::Success), ("OK".to_string(), None));
+        assert_eq!(status(TaskOutcome::Panicked("boom".to_string())), ("ERROR".to_string(), Some("boom".to_string())));
+        assert_eq!(
+            status(TaskOutcome::Timeout(Duration::from_millis(50))),
+            ("ERROR".to_string(), Some("timed out after 50ms".to_string()))
+        );
+    }
+}
@@ -1,11 +1,70 @@
+use crate::condition::TaskCondition;
+
 /// Metadata for a scheduled method inside an impl block
 #[derive(Debug, Clone, Copy)]
 pub struct ScheduledMethodMetadata {
     pub method_name: &'static str,
+    /// Explicit task name from `#[scheduled(name = "...")]`
+    pub name: Option<&'static str>,
     pub schedule_type: &'static str,
     pub schedule_value: &'static str,
     pub initial_delay: &'static str,
+    /// Running time after which a run is reported as stuck, empty for the scheduler default
+    pub warn_after: &'static str,
+    /// Longest time without a successful run before the task is overdue, empty for no limit
+    pub expect_success_within: &'static str,
+    /// Running time after which a run is cancelled, empty for no limit
+    pub timeout: &'static str,
     pub enabled: &'static str,
+    pub condition: Option<TaskCondition>,
     pub time_unit: &'static str,
     pub zone: &'static str,
+    pub tags: &'static [&'static str],
+    pub profiles: &'static [&'static str],
+    pub description: &'static str,
+    pub owner: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+}
+
+/// Where a task was declared (and registered, for instance tasks)
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSource {
+    pub file: &'static str,
+    pub line: u32,
+    /// Call site of `SchedulerBuilder::register`/`register_named`
+    pub registered_at: Option<&'static std::panic::Location<'static>>,
+}
+
+impl std::fmt::Display for TaskSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)?;
+        if let Some(location) = self.registered_at {
+            write!(f, " (registered at {}:{})", location.file(), location.line())?;
+        }
+        Ok(())
+    }
+}
+
+/// Descriptive metadata of a task known to the scheduler
+///
+/// Available through `SchedulerHandle::tasks()` for introspection.
+/// Schedule values are the raw (unresolved) values from the `#[scheduled]` attribute.
+#[derive(Debug, Clone)]
+pub struct TaskMetadata {
+    pub name: String,
+    pub schedule_type: &'static str,
+    pub schedule_value: &'static str,
+    pub tags: &'static [&'static str],
+    pub profiles: &'static [&'static str],
+    pub description: &'static str,
+    pub owner: &'static str,
+    pub source: TaskSource,
+}
+
+impl TaskMetadata {
+    /// Check if the task is labelled with the given tag
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
 }
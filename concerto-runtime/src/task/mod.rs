@@ -1,5 +1,7 @@
 mod scheduled;
 mod metadata;
+mod result;
 
 pub use scheduled::{ScheduledTask, ScheduledTaskBuilder};
-pub use metadata::ScheduledMethodMetadata;
+pub use metadata::{ScheduledMethodMetadata, TaskMetadata, TaskSource};
+pub use result::{IntoTaskResult, TaskFuture, TaskResult};
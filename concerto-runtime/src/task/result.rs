@@ -0,0 +1,36 @@
+use std::future::Future;
+use std::pin::Pin;
+
+/// Result of a single task run: `Err` carries the error message
+pub type TaskResult = Result<(), String>;
+
+/// Future returned by the handler of a `#[scheduled]` function or method
+pub type TaskFuture = Pin<Box<dyn Future<Output = TaskResult> + Send + 'static>>;
+
+/// Return values a `#[scheduled]` function or method may have
+///
+/// Tasks returning `()` always succeed. Tasks returning `Result` fail with the
+/// error's `Display` text, which shows up in logs, task info and history.
+///
+/// ```rust,ignore
+/// #[scheduled(fixed_rate = "1m")]
+/// async fn sync_users() -> Result<(), SyncError> {
+///     api.fetch_users().await?;
+///     Ok(())
+/// }
+/// ```
+pub trait IntoTaskResult {
+    fn into_task_result(self) -> TaskResult;
+}
+
+impl IntoTaskResult for () {
+    fn into_task_result(self) -> TaskResult {
+        Ok(())
+    }
+}
+
+impl<T, E: std::fmt::Display> IntoTaskResult for Result<T, E> {
+    fn into_task_result(self) -> TaskResult {
+        self.map(|_| ()).map_err(|e| e.to_string())
+    }
+}
@@ -1,3 +1,6 @@
+use super::result::TaskFuture;
+use crate::condition::TaskCondition;
+
 /// Represents a scheduled task
 #[derive(Debug, Clone)]
 pub struct ScheduledTask {
@@ -5,23 +8,46 @@ pub struct ScheduledTask {
     pub schedule_type: &'static str,
     pub schedule_value: &'static str,
     pub initial_delay: &'static str,
+    /// Running time after which a run is reported as stuck, empty for the scheduler default
+    pub warn_after: &'static str,
+    /// Longest time without a successful run before the task is overdue, empty for no limit
+    pub expect_success_within: &'static str,
+    /// Running time after which a run is cancelled, empty for no limit
+    pub timeout: &'static str,
     pub enabled: &'static str,
+    pub condition: Option<TaskCondition>,
     pub time_unit: &'static str,
     pub zone: &'static str,
-    pub handler: fn(),
+    pub tags: &'static [&'static str],
+    pub profiles: &'static [&'static str],
+    pub description: &'static str,
+    pub owner: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub handler: fn() -> TaskFuture,
 }
 
 impl ScheduledTask {
     /// Create a new builder for ScheduledTask
-    pub fn builder(name: &'static str, handler: fn()) -> ScheduledTaskBuilder {
+    pub fn builder(name: &'static str, handler: fn() -> TaskFuture) -> ScheduledTaskBuilder {
         ScheduledTaskBuilder {
             name,
             schedule_type: "cron",
             schedule_value: "0 0 * * * *",
             initial_delay: "0",
+            warn_after: "",
+            expect_success_within: "",
+            timeout: "",
             enabled: "true",
+            condition: None,
             time_unit: "seconds",
             zone: "UTC",
+            tags: &[],
+            profiles: &[],
+            description: "",
+            owner: "",
+            file: "",
+            line: 0,
             handler,
         }
     }
@@ -33,10 +59,20 @@ pub struct ScheduledTaskBuilder {
     schedule_type: &'static str,
     schedule_value: &'static str,
     initial_delay: &'static str,
+    warn_after: &'static str,
+    expect_success_within: &'static str,
+    timeout: &'static str,
     enabled: &'static str,
+    condition: Option<TaskCondition>,
     time_unit: &'static str,
     zone: &'static str,
-    handler: fn(),
+    tags: &'static [&'static str],
+    profiles: &'static [&'static str],
+    description: &'static str,
+    owner: &'static str,
+    file: &'static str,
+    line: u32,
+    handler: fn() -> TaskFuture,
 }
 
 impl ScheduledTaskBuilder {
@@ -55,11 +91,31 @@ impl ScheduledTaskBuilder {
         self
     }
 
+    pub fn warn_after(mut self, warn_after: &'static str) -> Self {
+        self.warn_after = warn_after;
+        self
+    }
+
+    pub fn expect_success_within(mut self, expect_success_within: &'static str) -> Self {
+        self.expect_success_within = expect_success_within;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: &'static str) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     pub fn enabled(mut self, enabled: &'static str) -> Self {
         self.enabled = enabled;
         self
     }
 
+    pub fn condition(mut self, condition: TaskCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
     pub fn time_unit(mut self, time_unit: &'static str) -> Self {
         self.time_unit = time_unit;
         self
@@ -70,15 +126,52 @@ impl ScheduledTaskBuilder {
         self
     }
 
+    pub fn tags(mut self, tags: &'static [&'static str]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn profiles(mut self, profiles: &'static [&'static str]) -> Self {
+        self.profiles = profiles;
+        self
+    }
+
+    pub fn description(mut self, description: &'static str) -> Self {
+        self.description = description;
+        self
+    }
+
+    pub fn owner(mut self, owner: &'static str) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Set the source location where the task is declared
+    pub fn location(mut self, file: &'static str, line: u32) -> Self {
+        self.file = file;
+        self.line = line;
+        self
+    }
+
     pub fn build(self) -> ScheduledTask {
         ScheduledTask {
             name: self.name,
             schedule_type: self.schedule_type,
             schedule_value: self.schedule_value,
             initial_delay: self.initial_delay,
+            warn_after: self.warn_after,
+            expect_success_within: self.expect_success_within,
+            timeout: self.timeout,
             enabled: self.enabled,
+            condition: self.condition,
             time_unit: self.time_unit,
             zone: self.zone,
+            tags: self.tags,
+            profiles: self.profiles,
+            description: self.description,
+            owner: self.owner,
+            file: self.file,
+            line: self.line,
             handler: self.handler,
         }
     }
@@ -82,7 +82,9 @@
 pub use concerto_macro::scheduled;
 
 // Re-export core types
-pub use concerto_runtime::{Runnable, SchedulerBuilder, TimeUnit};
+pub use concerto_runtime::{ConcertoError, Config, ConfigLoader, Runnable, SchedulerBuilder, TimeUnit};
+#[cfg(feature = "admin-http")]
+pub use concerto_runtime::AdminHttp;
 
 // Make concerto_runtime available for macro expansion
 pub use concerto_runtime;
\ No newline at end of file
@@ -0,0 +1,128 @@
+#![cfg(feature = "admin-http")]
+
+use concerto::{scheduled, AdminHttp, SchedulerBuilder};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const TOKEN: &str = "secret";
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "admin-probe", tags = ["admin-http"])]
+async fn admin_probe() {}
+
+/// Send one request and return the status and the parsed JSON body
+async fn request(addr: SocketAddr, head: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let request = format!("{}\r\nContent-Length: {}\r\n\r\n{}", head, body.len(), body);
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+
+    let status = response.split(' ').nth(1).unwrap().parse().unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    (status, serde_json::from_str(body).unwrap_or(Value::Null))
+}
+
+/// A request from a local client that sends the token
+async fn call(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+    let head = format!("{} {} HTTP/1.1\r\nHost: {}\r\nAuthorization: Bearer {}", method, path, addr, TOKEN);
+    request(addr, &head, body).await
+}
+
+#[tokio::test]
+async fn admin_http_endpoints() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["admin-http"])
+        .admin_http(AdminHttp::new("127.0.0.1:0").bearer_token(TOKEN).allow_host("scheduler.internal"))
+        .build()
+        .start()
+        .await
+        .unwrap();
+    let addr = handle.admin_http_addr().unwrap();
+
+    let (status, tasks) = call(addr, "GET", "/tasks", "").await;
+    assert_eq!(status, 200);
+    assert_eq!(tasks.as_array().unwrap().len(), 1);
+    assert_eq!(tasks[0]["name"], "admin-probe");
+    assert_eq!(tasks[0]["schedule"], "fixed_rate 3600000ms");
+
+    let (status, task) = call(addr, "POST", "/tasks/admin-probe/pause", "").await;
+    assert_eq!((status, task["state"].as_str()), (200, Some("paused")));
+    let (status, task) = call(addr, "POST", "/tasks/admin-probe/resume", "").await;
+    assert_eq!((status, task["state"].as_str()), (200, Some("idle")));
+
+    let (status, _) = call(addr, "POST", "/tasks/admin-probe/trigger", "").await;
+    assert_eq!(status, 202);
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let (status, history) = call(addr, "GET", "/tasks/admin-probe/history", "").await;
+    assert_eq!(status, 200);
+    assert_eq!(history[0]["outcome"], "success");
+
+    let schedule = json!({ "type": "fixed_rate", "value": "30s" }).to_string();
+    let (status, task) = call(addr, "PUT", "/tasks/admin-probe/schedule", &schedule).await;
+    assert_eq!((status, task["schedule"].as_str()), (200, Some("fixed_rate 30000ms")));
+    let (status, _) = call(addr, "PUT", "/tasks/admin-probe/schedule", r#"{"type":"cron","value":"nope"}"#).await;
+    assert_eq!(status, 400);
+
+    let (status, health) = call(addr, "GET", "/health", "").await;
+    assert_eq!(status, 200);
+    assert!(health["status"].is_string());
+
+    let (status, error) = call(addr, "GET", "/tasks/missing", "").await;
+    assert_eq!(status, 404);
+    assert!(error["error"].is_string());
+    let (status, _) = call(addr, "DELETE", "/tasks", "").await;
+    assert_eq!(status, 404);
+
+    handle.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn admin_http_rejects_unauthorized_and_foreign_requests() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["admin-http"])
+        .admin_http(AdminHttp::new("127.0.0.1:0").bearer_token(TOKEN).allow_host("scheduler.internal"))
+        .build()
+        .start()
+        .await
+        .unwrap();
+    let addr = handle.admin_http_addr().unwrap();
+
+    // Missing, wrong and URL tokens
+    let head = format!("GET /tasks HTTP/1.1\r\nHost: {}", addr);
+    assert_eq!(request(addr, &head, "").await.0, 401);
+    let head = format!("GET /tasks HTTP/1.1\r\nHost: {}\r\nAuthorization: Bearer wrong", addr);
+    assert_eq!(request(addr, &head, "").await.0, 401);
+    let head = format!("GET /tasks?access_token={} HTTP/1.1\r\nHost: {}", TOKEN, addr);
+    assert_eq!(request(addr, &head, "").await.0, 401);
+
+    // DNS rebinding: a name that is not allowed, even with the token
+    let auth = format!("Authorization: Bearer {}", TOKEN);
+    let head = format!("GET /tasks HTTP/1.1\r\nHost: evil.example:{}\r\n{}", addr.port(), auth);
+    assert_eq!(request(addr, &head, "").await.0, 403);
+    let head = format!("GET /tasks HTTP/1.1\r\n{}", auth);
+    assert_eq!(request(addr, &head, "").await.0, 403);
+    let head = format!("GET /tasks HTTP/1.1\r\nHost: scheduler.internal:{}\r\n{}", addr.port(), auth);
+    assert_eq!(request(addr, &head, "").await.0, 200);
+    let head = format!("GET /tasks HTTP/1.1\r\nHost: localhost:{}\r\n{}", addr.port(), auth);
+    assert_eq!(request(addr, &head, "").await.0, 200);
+
+    // Cross-site requests from a browser
+    let head = format!(
+        "POST /tasks/admin-probe/pause HTTP/1.1\r\nHost: {}\r\nOrigin: http://evil.example\r\n{}",
+        addr, auth
+    );
+    assert_eq!(request(addr, &head, "").await.0, 403);
+    let head = format!("GET /tasks HTTP/1.1\r\nHost: {}\r\nOrigin: http://{}\r\n{}", addr, addr, auth);
+    assert_eq!(request(addr, &head, "").await.0, 200);
+
+    // Too many headers
+    let mut head = format!("GET /tasks HTTP/1.1\r\nHost: {}\r\n{}", addr, auth);
+    for i in 0..100 {
+        head.push_str(&format!("\r\nX-Filler-{}: x", i));
+    }
+    assert_eq!(request(addr, &head, "").await.0, 400);
+
+    handle.shutdown().await.unwrap();
+}
@@ -0,0 +1,96 @@
+#![cfg(all(feature = "control-socket", unix))]
+
+use concerto::{scheduled, ConcertoError, SchedulerBuilder};
+use serde_json::{json, Value};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "socket-probe", tags = ["control-socket"])]
+async fn socket_probe() {}
+
+fn socket_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("concerto-{}-{}.sock", name, std::process::id()))
+}
+
+/// Send one request line and read the response line
+async fn send(path: &Path, request: Value) -> Value {
+    let mut stream = BufReader::new(UnixStream::connect(path).await.unwrap());
+    stream.get_mut().write_all(format!("{}\n", request).as_bytes()).await.unwrap();
+    let mut line = String::new();
+    stream.read_line(&mut line).await.unwrap();
+    serde_json::from_str(&line).unwrap()
+}
+
+async fn start(path: &Path) -> Result<concerto::concerto_runtime::SchedulerHandle, ConcertoError> {
+    SchedulerBuilder::new()
+        .include_tags(["control-socket"])
+        .control_socket(path)
+        .build()
+        .start()
+        .await
+}
+
+#[tokio::test]
+async fn control_socket_commands() {
+    let path = socket_path("commands");
+    let handle = start(&path).await.unwrap();
+
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    let response = send(&path, json!({ "command": "list" })).await;
+    assert_eq!(response["ok"], true);
+    assert_eq!(response["result"][0]["name"], "socket-probe");
+
+    let response = send(&path, json!({ "command": "pause", "task": "socket-probe" })).await;
+    assert_eq!(response["result"]["state"], "paused");
+    let response = send(&path, json!({ "command": "resume", "task": "socket-probe" })).await;
+    assert_eq!(response["result"]["state"], "idle");
+
+    let response = send(&path, json!({ "command": "trigger", "task": "socket-probe" })).await;
+    assert_eq!(response["ok"], true);
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let response = send(&path, json!({ "command": "status", "task": "socket-probe" })).await;
+    assert_eq!(response["result"]["history"][0]["outcome"], "success");
+    let response = send(&path, json!({ "command": "status" })).await;
+    assert!(response["result"]["status"].is_string());
+
+    let response = send(&path, json!({ "command": "pause" })).await;
+    assert_eq!(response["ok"], false);
+    let response = send(&path, json!({ "command": "pause", "task": "missing" })).await;
+    assert_eq!(response["ok"], false);
+    let response = send(&path, json!({ "command": "explode" })).await;
+    assert_eq!(response, json!({ "ok": false, "error": "unknown command 'explode'" }));
+
+    let response = send(&path, json!({ "command": "drain", "timeout_ms": 500 })).await;
+    assert_eq!(response["result"], json!({ "drained": true, "running": [] }));
+
+    handle.shutdown().await.unwrap();
+    assert!(!path.exists(), "socket file left behind after shutdown");
+}
+
+#[tokio::test]
+async fn control_socket_refuses_paths_in_use() {
+    // A regular file is never replaced
+    let path = socket_path("file");
+    std::fs::write(&path, "keep me").unwrap();
+    assert!(matches!(start(&path).await, Err(ConcertoError::Listen { .. })));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep me");
+    std::fs::remove_file(&path).unwrap();
+
+    // Neither is a socket another scheduler listens on
+    let path = socket_path("busy");
+    let handle = start(&path).await.unwrap();
+    assert!(matches!(start(&path).await, Err(ConcertoError::Listen { .. })));
+    assert_eq!(send(&path, json!({ "command": "list" })).await["ok"], true);
+    handle.shutdown().await.unwrap();
+
+    // A stale socket from a process that is gone is replaced
+    let path = socket_path("stale");
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+    let handle = start(&path).await.unwrap();
+    assert_eq!(send(&path, json!({ "command": "list" })).await["ok"], true);
+    handle.shutdown().await.unwrap();
+}
@@ -0,0 +1,44 @@
+use concerto::concerto_runtime::TaskState;
+use concerto::{scheduled, SchedulerBuilder};
+use std::time::{Duration, Instant};
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "drain-slow", tags = ["drain"])]
+async fn drain_slow() {
+    tokio::time::sleep(Duration::from_millis(800)).await;
+}
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "drain-idle", tags = ["drain"])]
+async fn drain_idle() {}
+
+#[tokio::test]
+async fn drain_reports_runs_outliving_the_timeout() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["drain"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    handle.trigger("drain-slow").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let clock = Instant::now();
+    assert_eq!(handle.drain(Duration::from_millis(200)).await, ["drain-slow"]);
+    let waited = clock.elapsed();
+    assert!(waited >= Duration::from_millis(200) && waited < Duration::from_millis(700), "waited {:?}", waited);
+
+    // Every task is paused, the run in progress is left to finish
+    for name in ["drain-slow", "drain-idle"] {
+        assert_eq!(handle.task_info(name).await.unwrap().state, TaskState::Paused, "{}", name);
+    }
+    assert_eq!(handle.task_info("drain-slow").await.unwrap().success_count, 0);
+
+    // Drained as soon as the run completes, well before the timeout
+    let clock = Instant::now();
+    assert!(handle.drain(Duration::from_secs(5)).await.is_empty());
+    assert!(clock.elapsed() < Duration::from_secs(2), "waited {:?}", clock.elapsed());
+    assert_eq!(handle.task_info("drain-slow").await.unwrap().success_count, 1);
+
+    handle.resume("drain-slow").await.unwrap();
+    assert_eq!(handle.task_info("drain-slow").await.unwrap().state, TaskState::Idle);
+    handle.shutdown().await.unwrap();
+}
@@ -0,0 +1,74 @@
+use concerto::{scheduled, ConcertoError, SchedulerBuilder};
+
+mod billing {
+    use concerto::scheduled;
+
+    #[scheduled(cron = "0 0 1 * * *", name = "nightly-report", tags = ["duplicate"])]
+    pub async fn report() {}
+}
+
+mod sales {
+    use concerto::scheduled;
+
+    #[scheduled(cron = "0 0 2 * * *", name = "nightly-report", tags = ["duplicate", "sales"])]
+    pub async fn report() {}
+}
+
+struct TenantSync;
+
+#[scheduled]
+impl TenantSync {
+    #[scheduled(fixed_rate = "1h", tags = ["duplicate-instance"])]
+    async fn sync(&self) {}
+}
+
+#[test]
+fn try_build_reports_every_clash() {
+    let duplicates = match SchedulerBuilder::new().include_tags(["duplicate"]).try_build() {
+        Err(ConcertoError::DuplicateTaskNames(duplicates)) => duplicates,
+        Err(e) => panic!("expected DuplicateTaskNames, got {}", e),
+        Ok(_) => panic!("expected DuplicateTaskNames, the scheduler was built"),
+    };
+    assert_eq!(duplicates.len(), 1);
+    let (name, sources) = &duplicates[0];
+    assert_eq!(name, "nightly-report");
+    assert_eq!(sources.len(), 2);
+    assert!(sources.iter().all(|source| source.file.ends_with("duplicate_names.rs") && source.registered_at.is_none()));
+    assert_ne!(sources[0].line, sources[1].line);
+
+    // Names are checked after the tag filters
+    assert!(SchedulerBuilder::new().include_tags(["sales"]).try_build().is_ok());
+}
+
+#[test]
+fn try_build_reports_instances_registered_under_the_same_name() {
+    let first_line = line!() + 3;
+    let built = SchedulerBuilder::new()
+        .include_tags(["duplicate-instance"])
+        .register_named(TenantSync, "tenant_a")
+        .register_named(TenantSync, "tenant_a")
+        .try_build();
+    let Err(ConcertoError::DuplicateTaskNames(duplicates)) = built else {
+        panic!("expected DuplicateTaskNames");
+    };
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].0, "tenant_a::sync");
+    let registered_at: Vec<u32> = duplicates[0].1.iter().filter_map(|source| source.registered_at).map(|at| at.line()).collect();
+    assert_eq!(registered_at, [first_line, first_line + 1]);
+
+    let message = ConcertoError::DuplicateTaskNames(duplicates).to_string();
+    assert!(message.contains("Task name 'tenant_a::sync' is used 2 times"), "{}", message);
+
+    assert!(SchedulerBuilder::new()
+        .include_tags(["duplicate-instance"])
+        .register_named(TenantSync, "tenant_a")
+        .register_named(TenantSync, "tenant_b")
+        .try_build()
+        .is_ok());
+}
+
+#[test]
+#[should_panic(expected = "Duplicate task names found")]
+fn build_panics_on_duplicate_names() {
+    SchedulerBuilder::new().include_tags(["duplicate"]).build();
+}
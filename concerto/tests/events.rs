@@ -0,0 +1,123 @@
+use concerto::concerto_runtime::SchedulerEvent;
+use concerto::{scheduled, SchedulerBuilder};
+use std::time::Duration;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio::sync::broadcast::Receiver;
+
+#[scheduled(fixed_rate = "100ms", name = "events-tick", tags = ["events"])]
+async fn events_tick() {}
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "events-error", tags = ["events"])]
+async fn events_error() -> Result<(), String> {
+    Err("quota exceeded".to_string())
+}
+
+#[scheduled(fixed_rate = "1h", enabled = "false", name = "events-off", tags = ["events"])]
+async fn events_off() {}
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "events-flood", tags = ["events-flood"])]
+async fn events_flood() {}
+
+async fn next(events: &mut Receiver<SchedulerEvent>) -> SchedulerEvent {
+    tokio::time::timeout(Duration::from_secs(2), events.recv())
+        .await
+        .expect("no event within 2s")
+        .expect("event stream closed or lagged")
+}
+
+/// Next event about `task`, skipping the others
+async fn next_of(events: &mut Receiver<SchedulerEvent>, task: &str) -> SchedulerEvent {
+    loop {
+        let event = next(events).await;
+        if event.task() == Some(task) {
+            return event;
+        }
+    }
+}
+
+#[tokio::test]
+async fn events_follow_the_lifecycle_in_order() {
+    let scheduler = SchedulerBuilder::new().include_tags(["events"]).build();
+    let mut events = scheduler.subscribe();
+    let handle = scheduler.start().await.unwrap();
+
+    // Registrations and disabled tasks come before SchedulerStarted
+    let mut startup = Vec::new();
+    loop {
+        match next(&mut events).await {
+            SchedulerEvent::SchedulerStarted { .. } => break,
+            SchedulerEvent::TaskRegistered { task, schedule, .. } => startup.push(format!("registered {} {}", task, schedule)),
+            SchedulerEvent::TaskDisabled { task, .. } => startup.push(format!("disabled {}", task)),
+            other => panic!("unexpected event before SchedulerStarted: {:?}", other),
+        }
+    }
+    startup.sort();
+    assert_eq!(
+        startup,
+        [
+            "disabled events-off",
+            "registered events-error fixed_rate 3600000ms",
+            "registered events-tick fixed_rate 100ms",
+        ]
+    );
+
+    // A fire: fired, started, then completed, all for the same fire time
+    let SchedulerEvent::TaskFired { scheduled_at: fired, .. } = next_of(&mut events, "events-tick").await else {
+        panic!("expected TaskFired first");
+    };
+    let SchedulerEvent::TaskStarted { scheduled_at: started, .. } = next_of(&mut events, "events-tick").await else {
+        panic!("expected TaskStarted after TaskFired");
+    };
+    let SchedulerEvent::TaskCompleted { execution, .. } = next_of(&mut events, "events-tick").await else {
+        panic!("expected TaskCompleted after TaskStarted");
+    };
+    assert_eq!(fired, started);
+    assert_eq!(execution.scheduled_at, fired);
+
+    // A manual run is not fired, it starts right away
+    handle.trigger("events-error").await.unwrap();
+    assert!(matches!(next_of(&mut events, "events-error").await, SchedulerEvent::TaskStarted { .. }));
+    let SchedulerEvent::TaskFailed { execution, .. } = next_of(&mut events, "events-error").await else {
+        panic!("expected TaskFailed after TaskStarted");
+    };
+    assert_eq!(execution.error(), Some("quota exceeded"));
+
+    handle.shutdown().await.unwrap();
+    while !matches!(next(&mut events).await, SchedulerEvent::SchedulerStopped { .. }) {}
+    assert!(matches!(events.try_recv(), Err(TryRecvError::Empty | TryRecvError::Closed)), "event after SchedulerStopped");
+}
+
+#[tokio::test]
+async fn lagging_subscriber_skips_the_oldest_events() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["events-flood"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    let mut slow = handle.subscribe();
+
+    // 150 runs emit 300 events, more than the 256 a receiver buffers
+    for _ in 0..150 {
+        handle.trigger("events-flood").await.unwrap();
+    }
+    while handle.task_info("events-flood").await.unwrap().success_count < 150 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    // The count is updated just before the last event is sent
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert!(matches!(slow.recv().await, Err(RecvError::Lagged(44))));
+    let mut received = 0;
+    while let Ok(event) = slow.try_recv() {
+        assert_eq!(event.task(), Some("events-flood"));
+        received += 1;
+    }
+    assert_eq!(received, 256);
+
+    // The receiver keeps working once it caught up
+    handle.trigger("events-flood").await.unwrap();
+    assert!(matches!(next(&mut slow).await, SchedulerEvent::TaskStarted { .. }));
+    assert!(matches!(next(&mut slow).await, SchedulerEvent::TaskCompleted { .. }));
+    handle.shutdown().await.unwrap();
+}
@@ -0,0 +1,46 @@
+use concerto::{scheduled, ConcertoError, SchedulerBuilder};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+#[scheduled(fixed_rate = "50ms", tags = ["fail-fast"])]
+async fn healthy() {
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[scheduled(fixed_rate = "${jobs.missing}", tags = ["fail-fast"])]
+async fn broken() {}
+
+#[tokio::test]
+async fn fail_fast_leaves_no_task_running() {
+    let started = SchedulerBuilder::new()
+        .include_tags(["fail-fast"])
+        .fail_fast(true)
+        .build()
+        .start()
+        .await;
+    match started {
+        Err(ConcertoError::StartFailed(report)) => {
+            assert_eq!(report.failed.len(), 1);
+            assert!(report.failed[0].name.contains("broken"), "unexpected task {}", report.failed[0].name);
+        }
+        Err(e) => panic!("expected StartFailed, got {}", e),
+        Ok(_) => panic!("expected StartFailed, scheduler started"),
+    }
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(RUNS.load(Ordering::SeqCst), 0, "a task ran after fail_fast aborted the start");
+
+    // Without fail_fast the healthy task is scheduled and the failure is only reported
+    let handle = SchedulerBuilder::new()
+        .include_tags(["fail-fast"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    assert_eq!(handle.start_report().failed.len(), 1);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(RUNS.load(Ordering::SeqCst) > 0);
+    handle.shutdown().await.unwrap();
+}
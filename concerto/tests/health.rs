@@ -0,0 +1,115 @@
+use concerto::concerto_runtime::{HealthStatus, SchedulerEvent, TaskState};
+use concerto::{scheduled, SchedulerBuilder};
+use std::time::Duration;
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "health-failing", tags = ["health-failing"])]
+async fn health_failing() -> Result<(), String> {
+    Err("upstream down".to_string())
+}
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", warn_after = "100ms", name = "health-stuck", tags = ["health-stuck"])]
+async fn health_stuck() {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+}
+
+#[scheduled(
+    fixed_rate = "1h",
+    initial_delay = "1h",
+    expect_success_within = "200ms",
+    name = "health-overdue",
+    tags = ["health-overdue"]
+)]
+async fn health_overdue() {}
+
+#[tokio::test]
+async fn failed_run_degrades_health() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["health-failing"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    let health = handle.health().await;
+    assert_eq!(health.status, HealthStatus::Healthy, "{}", health);
+
+    handle.trigger("health-failing").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let health = handle.health().await;
+    assert_eq!(health.status, HealthStatus::Degraded, "{}", health);
+    assert!(health.is_alive() && !health.is_healthy());
+    assert_eq!(health.failing, ["health-failing"]);
+    assert!(health.stuck.is_empty() && health.overdue.is_empty() && health.dead_loops.is_empty());
+    handle.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn watchdog_reports_stuck_runs() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["health-stuck"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    let mut events = handle.subscribe();
+    handle.trigger("health-stuck").await.unwrap();
+
+    // The watchdog checks once per second
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(3);
+    loop {
+        match tokio::time::timeout_at(deadline, events.recv()).await {
+            Ok(Ok(SchedulerEvent::TaskStuck { task, elapsed, .. })) => {
+                assert_eq!(task, "health-stuck");
+                assert!(elapsed > Duration::from_millis(100));
+                break;
+            }
+            Ok(Ok(_)) => {}
+            other => panic!("no TaskStuck event: {:?}", other),
+        }
+    }
+
+    let health = handle.health().await;
+    assert_eq!(health.status, HealthStatus::Degraded, "{}", health);
+    assert_eq!(health.stuck, ["health-stuck"]);
+    assert!(health.failing.is_empty() && health.overdue.is_empty());
+    assert_eq!(handle.task_info("health-stuck").await.unwrap().state, TaskState::Stuck);
+    handle.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn watchdog_reports_overdue_tasks() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["health-overdue"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    let mut events = handle.subscribe();
+
+    // Never fired, so the window counts from the start
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(3);
+    loop {
+        match tokio::time::timeout_at(deadline, events.recv()).await {
+            Ok(Ok(SchedulerEvent::Overdue { task, last_success, expected_within, .. })) => {
+                assert_eq!(task, "health-overdue");
+                assert_eq!(last_success, None);
+                assert_eq!(expected_within, Duration::from_millis(200));
+                break;
+            }
+            Ok(Ok(_)) => {}
+            other => panic!("no Overdue event: {:?}", other),
+        }
+    }
+
+    let health = handle.health().await;
+    assert_eq!(health.status, HealthStatus::Degraded, "{}", health);
+    assert_eq!(health.overdue, ["health-overdue"]);
+    assert!(health.failing.is_empty() && health.stuck.is_empty());
+
+    // A success clears it
+    handle.trigger("health-overdue").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let health = handle.health().await;
+    assert_eq!(health.status, HealthStatus::Healthy, "{}", health);
+    handle.shutdown().await.unwrap();
+}
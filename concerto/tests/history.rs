@@ -0,0 +1,64 @@
+use concerto::concerto_runtime::SchedulerHandle;
+use concerto::{scheduled, SchedulerBuilder};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "numbered", tags = ["history"])]
+async fn numbered() -> Result<(), String> {
+    Err(format!("run {}", RUNS.fetch_add(1, Ordering::SeqCst) + 1))
+}
+
+/// Trigger `runs` runs one after the other
+async fn run_times(handle: &SchedulerHandle, runs: usize) {
+    for _ in 0..runs {
+        let before = RUNS.load(Ordering::SeqCst);
+        handle.trigger("numbered").await.unwrap();
+        while RUNS.load(Ordering::SeqCst) == before {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
+    // Let the last run be recorded
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+async fn errors(handle: &SchedulerHandle) -> Vec<String> {
+    let history = handle.history("numbered").await.unwrap();
+    history.iter().map(|run| run.error().unwrap_or_default().to_string()).collect()
+}
+
+#[tokio::test]
+async fn history_keeps_the_latest_runs() {
+    let handle = SchedulerBuilder::new()
+        .include_tags(["history"])
+        .history_size(3)
+        .build()
+        .start()
+        .await
+        .unwrap();
+    assert!(handle.history("numbered").await.unwrap().is_empty());
+    assert!(handle.history("missing").await.is_none());
+
+    run_times(&handle, 2).await;
+    assert_eq!(errors(&handle).await, ["run 1", "run 2"]);
+
+    // Oldest first, the oldest is dropped once full
+    run_times(&handle, 3).await;
+    assert_eq!(errors(&handle).await, ["run 3", "run 4", "run 5"]);
+    let info = handle.task_info("numbered").await.unwrap();
+    assert_eq!(info.failure_count, 5, "counters include evicted runs");
+    handle.shutdown().await.unwrap();
+
+    let handle = SchedulerBuilder::new()
+        .include_tags(["history"])
+        .history_size(0)
+        .build()
+        .start()
+        .await
+        .unwrap();
+    run_times(&handle, 2).await;
+    assert!(handle.history("numbered").await.unwrap().is_empty());
+    assert_eq!(handle.task_info("numbered").await.unwrap().failure_count, 2);
+    handle.shutdown().await.unwrap();
+}
@@ -0,0 +1,125 @@
+use concerto::concerto_runtime::{Schedule, TaskExecution, TaskListener};
+use concerto::{scheduled, Runnable, SchedulerBuilder};
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "listen-ok", tags = ["listener"])]
+async fn listen_ok() {}
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "listen-error", tags = ["listener"])]
+async fn listen_error() -> Result<(), String> {
+    Err("disk full".to_string())
+}
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "listen-panic", tags = ["listener"])]
+async fn listen_panic() {
+    panic!("boom");
+}
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", timeout = "50ms", name = "listen-timeout", tags = ["listener"])]
+async fn listen_timeout() {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+}
+
+#[scheduled(fixed_rate = "100ms", name = "listen-paused", tags = ["listener"])]
+async fn listen_paused() {}
+
+// Runs overlap: the second fire starts while the first run is still going
+#[scheduled(fixed_rate = "100ms", name = "listen-overlap", tags = ["listener"])]
+async fn listen_overlap() {
+    tokio::time::sleep(Duration::from_millis(250)).await;
+}
+
+struct Blocking;
+
+#[scheduled(fixed_rate = "1h", initial_delay = "1h", name = "listen-runnable", tags = ["listener"])]
+impl Runnable for Blocking {
+    fn run(&self) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Records `callback outcome` per task, in call order
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<(String, String)>>>);
+
+impl Recorder {
+    fn push(&self, task: &str, event: String) {
+        self.0.lock().unwrap().push((task.to_string(), event));
+    }
+
+    fn events(&self, task: &str) -> Vec<String> {
+        let events = self.0.lock().unwrap();
+        events.iter().filter(|(name, _)| name == task).map(|(_, event)| event.clone()).collect()
+    }
+}
+
+impl TaskListener for Recorder {
+    fn on_scheduled(&self, task: &str, schedule: &str) {
+        self.push(task, format!("on_scheduled {}", schedule));
+    }
+
+    fn on_start(&self, task: &str, _scheduled_at: DateTime<Utc>) {
+        self.push(task, "on_start".to_string());
+    }
+
+    fn on_success(&self, task: &str, execution: &TaskExecution) {
+        self.push(task, format!("on_success {}", execution.outcome));
+    }
+
+    fn on_failure(&self, task: &str, execution: &TaskExecution) {
+        self.push(task, format!("on_failure {}", execution.outcome));
+    }
+
+    fn on_skipped(&self, task: &str, execution: &TaskExecution) {
+        self.push(task, format!("on_skipped {}", execution.outcome));
+    }
+
+    fn on_timeout(&self, task: &str, execution: &TaskExecution) {
+        self.push(task, format!("on_timeout {}", execution.outcome));
+    }
+}
+
+#[tokio::test]
+async fn listener_sees_every_execution_path() {
+    let recorder = Recorder::default();
+    let handle = SchedulerBuilder::new()
+        .include_tags(["listener"])
+        .register(Blocking)
+        .listener(recorder.clone())
+        .build()
+        .start()
+        .await
+        .unwrap();
+    handle.pause("listen-paused").await.unwrap();
+
+    for task in ["listen-ok", "listen-error", "listen-panic", "listen-timeout", "listen-runnable"] {
+        handle.trigger(task).await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    let hourly = "on_scheduled fixed_rate 3600000ms";
+    assert_eq!(recorder.events("listen-ok"), [hourly, "on_start", "on_success success"]);
+    assert_eq!(recorder.events("listen-error"), [hourly, "on_start", "on_failure failed: disk full"]);
+    assert_eq!(recorder.events("listen-panic"), [hourly, "on_start", "on_failure panicked: boom"]);
+    assert_eq!(recorder.events("listen-timeout"), [hourly, "on_start", "on_timeout timed out after 50ms"]);
+    assert_eq!(recorder.events("listen-runnable"), [hourly, "on_start", "on_success success"]);
+
+    // Fires while paused are skipped without starting a run
+    let paused = recorder.events("listen-paused");
+    assert_eq!(paused[0], "on_scheduled fixed_rate 100ms");
+    assert!(paused.len() >= 3, "{:?}", paused);
+    assert!(paused[1..].iter().all(|event| event == "on_skipped skipped: paused"), "{:?}", paused);
+
+    // Overlapping runs are not skipped: both start before the first one finishes
+    let overlap = recorder.events("listen-overlap");
+    assert_eq!(overlap[..3], ["on_scheduled fixed_rate 100ms", "on_start", "on_start"]);
+    assert!(!overlap.iter().any(|event| event.starts_with("on_skipped")), "{:?}", overlap);
+
+    // Rescheduling announces the new schedule
+    handle.reschedule("listen-ok", &Schedule::parse("fixed_rate", "2h").unwrap()).await.unwrap();
+    assert_eq!(recorder.events("listen-ok").last().unwrap(), "on_scheduled fixed_rate 7200000ms");
+
+    handle.shutdown().await.unwrap();
+}
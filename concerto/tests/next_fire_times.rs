@@ -0,0 +1,60 @@
+use chrono::{Duration as ChronoDuration, Utc};
+use concerto::{scheduled, SchedulerBuilder};
+use std::time::Duration;
+
+#[scheduled(fixed_rate = "100ms", name = "preview-rate", tags = ["preview"])]
+async fn preview_rate() {}
+
+#[scheduled(fixed_rate = "1m", initial_delay = "1h", name = "preview-delayed", tags = ["preview"])]
+async fn preview_delayed() {}
+
+#[scheduled(fixed_delay = "1h", name = "preview-delay", tags = ["preview"])]
+async fn preview_delay() {}
+
+#[scheduled(cron = "0 0 9 * * *", zone = "Asia/Jakarta", name = "preview-cron", tags = ["preview"])]
+async fn preview_cron() {}
+
+#[tokio::test]
+async fn next_fire_times_of_running_tasks() {
+    let started = Utc::now();
+    let handle = SchedulerBuilder::new().include_tags(["preview"]).build().start().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(250)).await;
+
+    // Not run yet: fixed_rate first fires one interval after the initial delay
+    let first = ChronoDuration::hours(1) + ChronoDuration::minutes(1);
+    let delayed = handle.next_fire_times("preview-delayed", 3).await.unwrap();
+    assert_eq!(delayed.len(), 3);
+    assert!(delayed[0] >= started + first);
+    assert!(delayed[0] <= Utc::now() + first);
+    assert_eq!(delayed[1] - delayed[0], ChronoDuration::minutes(1));
+    assert_eq!(delayed[2] - delayed[1], ChronoDuration::minutes(1));
+
+    // Already running: one interval apart, the first within the next interval
+    let now = Utc::now();
+    let rate = handle.next_fire_times("preview-rate", 2).await.unwrap();
+    assert!(rate[0] > now);
+    assert!(rate[0] <= now + ChronoDuration::milliseconds(100));
+    assert_eq!(rate[1] - rate[0], ChronoDuration::milliseconds(100));
+
+    // Ran at start: counted from the end of that run
+    let info = handle.task_info("preview-delay").await.unwrap();
+    let delay = handle.next_fire_times("preview-delay", 2).await.unwrap();
+    assert_eq!(delay[0], info.last_finished.unwrap() + ChronoDuration::hours(1));
+    assert_eq!(delay[1] - delay[0], ChronoDuration::hours(1));
+
+    // 09:00 in Jakarta is 02:00 UTC
+    let cron = handle.next_fire_times("preview-cron", 2).await.unwrap();
+    assert_eq!(cron[0].format("%H:%M:%S").to_string(), "02:00:00");
+    assert_eq!(cron[1] - cron[0], ChronoDuration::days(1));
+    assert_eq!(handle.task_info("preview-cron").await.unwrap().next_fire, Some(cron[0]));
+
+    assert_eq!(handle.next_fire_times("preview-cron", 0).await, Some(Vec::new()));
+    assert_eq!(handle.next_fire_times("no-such-task", 3).await, None);
+
+    handle.pause("preview-cron").await.unwrap();
+    assert_eq!(handle.next_fire_times("preview-cron", 3).await, Some(Vec::new()));
+    handle.resume("preview-cron").await.unwrap();
+    assert_eq!(handle.next_fire_times("preview-cron", 1).await, Some(vec![cron[0]]));
+
+    handle.shutdown().await.unwrap();
+}
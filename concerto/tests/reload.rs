@@ -0,0 +1,117 @@
+use concerto::concerto_runtime::TaskState;
+use concerto::{scheduled, ConcertoError, SchedulerBuilder};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+static TICKS: AtomicUsize = AtomicUsize::new(0);
+
+#[scheduled(fixed_rate = "${jobs.rate}", tags = ["reload"])]
+async fn ticker() {
+    TICKS.fetch_add(1, Ordering::SeqCst);
+}
+
+fn config_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("concerto-{}-{}.toml", name, std::process::id()))
+}
+
+fn write_rate(path: &PathBuf, rate: &str) {
+    std::fs::write(path, format!("[jobs]\nrate = \"{}\"\n", rate)).unwrap();
+}
+
+async fn schedule_of(handle: &concerto::concerto_runtime::SchedulerHandle) -> Option<String> {
+    handle.task_info("ticker").await.and_then(|info| info.schedule)
+}
+
+#[tokio::test]
+async fn reload_keeps_previous_schedule_when_config_is_invalid() {
+    let path = config_file("reload");
+    write_rate(&path, "100ms");
+
+    let handle = SchedulerBuilder::with_toml(path.to_str().unwrap())
+        .include_tags(["reload"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    assert_eq!(schedule_of(&handle).await.as_deref(), Some("fixed_rate 100ms"));
+
+    write_rate(&path, "nope");
+    match handle.reload_config().await {
+        Err(ConcertoError::ReloadIncomplete(failures)) => {
+            assert_eq!(failures.len(), 1);
+            assert!(failures[0].0.contains("ticker"), "unexpected task {}", failures[0].0);
+        }
+        other => panic!("expected ReloadIncomplete, got {:?}", other),
+    }
+    assert_eq!(schedule_of(&handle).await.as_deref(), Some("fixed_rate 100ms"));
+
+    // The task keeps running on its previous schedule
+    let ticks = TICKS.load(Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(350)).await;
+    assert!(TICKS.load(Ordering::SeqCst) > ticks, "task stopped after a failed reload");
+
+    write_rate(&path, "0ms");
+    assert!(matches!(handle.reload_config().await, Err(ConcertoError::ReloadIncomplete(_))));
+    assert_eq!(schedule_of(&handle).await.as_deref(), Some("fixed_rate 100ms"));
+
+    write_rate(&path, "200ms");
+    handle.reload_config().await.unwrap();
+    assert_eq!(schedule_of(&handle).await.as_deref(), Some("fixed_rate 200ms"));
+
+    handle.shutdown().await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[scheduled(cron = "${toggle.cron}", enabled = "${toggle.enabled}", tags = ["reload-toggle"])]
+async fn toggled() {}
+
+fn write_toggle(path: &PathBuf, enabled: bool, cron: &str) {
+    std::fs::write(path, format!("[toggle]\nenabled = \"{}\"\ncron = \"{}\"\n", enabled, cron)).unwrap();
+}
+
+async fn toggled_state(handle: &concerto::concerto_runtime::SchedulerHandle) -> (bool, TaskState, Option<String>) {
+    let info = handle.task_info("toggled").await.unwrap();
+    (info.enabled, info.state, info.schedule)
+}
+
+#[tokio::test]
+async fn reload_enables_and_disables_tasks() {
+    let path = config_file("reload-toggle");
+    let hourly = Some("cron '0 0 * * * *' (UTC)".to_string());
+    write_toggle(&path, false, "0 0 * * * *");
+
+    let handle = SchedulerBuilder::with_toml(path.to_str().unwrap())
+        .include_tags(["reload-toggle"])
+        .build()
+        .start()
+        .await
+        .unwrap();
+    assert_eq!(toggled_state(&handle).await, (false, TaskState::Disabled, None));
+
+    // Enabled by config, but the schedule cannot be launched: still disabled
+    write_toggle(&path, true, "nope");
+    assert!(matches!(handle.reload_config().await, Err(ConcertoError::ReloadIncomplete(_))));
+    assert_eq!(toggled_state(&handle).await, (false, TaskState::Disabled, None));
+
+    write_toggle(&path, true, "0 0 * * * *");
+    handle.reload_config().await.unwrap();
+    assert_eq!(toggled_state(&handle).await, (true, TaskState::Idle, hourly.clone()));
+
+    // A schedule that fails while enabled keeps the running job
+    write_toggle(&path, true, "nope");
+    assert!(matches!(handle.reload_config().await, Err(ConcertoError::ReloadIncomplete(_))));
+    assert_eq!(toggled_state(&handle).await, (true, TaskState::Idle, hourly.clone()));
+
+    write_toggle(&path, false, "0 0 * * * *");
+    handle.reload_config().await.unwrap();
+    assert_eq!(toggled_state(&handle).await, (false, TaskState::Disabled, None));
+
+    write_toggle(&path, true, "0 30 * * * *");
+    handle.reload_config().await.unwrap();
+    let half_past = Some("cron '0 30 * * * *' (UTC)".to_string());
+    assert_eq!(toggled_state(&handle).await, (true, TaskState::Idle, half_past));
+
+    handle.shutdown().await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,247 @@
+//! `concertoctl` - manage a running Concerto scheduler through its control socket
+//!
+//! The application enables the socket with `SchedulerBuilder::control_socket`
+//! (feature `control-socket`). Pass its path with `--socket` or `CONCERTO_SOCKET`.
+
+use serde_json::{json, Value};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: concertoctl [--socket PATH] [--json] <command> [args]
+
+Commands:
+  list                     Tasks with their state, schedule and next fire time
+  status [TASK]            Scheduler health, or a task and its last runs
+  trigger TASK             Run a task once now
+  pause TASK               Skip the task's fire times until resumed
+  resume TASK              Resume a paused task
+  reload-config            Reload the config files and reschedule changed tasks
+  drain [--timeout SECS]   Pause every task and wait for running ones (default 30s)
+
+Options:
+  --socket PATH   Control socket of the application (default: $CONCERTO_SOCKET)
+  --json          Print the raw JSON result
+  -h, --help      Show this help";
+
+/// Environment variable holding the socket path when `--socket` is not given
+const SOCKET_ENV: &str = "CONCERTO_SOCKET";
+
+struct Args {
+    socket: String,
+    json: bool,
+    request: Value,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let response = match send(&args.socket, &args.request) {
+        Ok(response) => response,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+    if response["ok"].as_bool() != Some(true) {
+        eprintln!("error: {}", response["error"].as_str().unwrap_or("invalid response"));
+        return ExitCode::FAILURE;
+    }
+
+    let result = &response["result"];
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(result).unwrap_or_default());
+    } else {
+        print_result(&args.request, result);
+    }
+    // A drain that timed out is reported as a failure, so scripts can stop there
+    if result["drained"] == json!(false) {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// `None` when help was requested
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut socket = std::env::var(SOCKET_ENV).ok();
+    let mut json = false;
+    let mut timeout_secs = None;
+    let mut positional = Vec::new();
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--json" => json = true,
+            "--socket" => socket = Some(args.next().ok_or("--socket needs a path")?),
+            "--timeout" => {
+                let value = args.next().ok_or("--timeout needs a number of seconds")?;
+                let secs: u64 = value.parse().map_err(|_| format!("invalid --timeout '{}'", value))?;
+                timeout_secs = Some(secs);
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let socket = socket.ok_or(format!("no socket given, use --socket or set {}", SOCKET_ENV))?;
+    let (command, task) = match positional.as_slice() {
+        [] => return Err("missing command".to_string()),
+        [command] => (command.as_str(), None),
+        [command, task] => (command.as_str(), Some(task.as_str())),
+        _ => return Err("too many arguments".to_string()),
+    };
+
+    let request = match (command, task) {
+        ("list" | "reload-config", None) | ("status", _) => json!({ "command": command, "task": task }),
+        ("trigger" | "pause" | "resume", Some(task)) => json!({ "command": command, "task": task }),
+        ("trigger" | "pause" | "resume", None) => return Err(format!("'{}' needs a task name", command)),
+        ("drain", None) => {
+            json!({ "command": "drain", "timeout_ms": timeout_secs.map(|secs| secs * 1000) })
+        }
+        ("list" | "reload-config" | "drain", Some(_)) => {
+            return Err(format!("'{}' takes no task name", command))
+        }
+        _ => return Err(format!("unknown command '{}'", command)),
+    };
+    Ok(Some(Args { socket, json, request }))
+}
+
+/// Send one request line and read the response line
+#[cfg(unix)]
+fn send(socket: &str, request: &Value) -> Result<Value, String> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket).map_err(|e| format!("cannot connect to {}: {}", socket, e))?;
+    writeln!(stream, "{}", request).map_err(|e| format!("cannot send the request: {}", e))?;
+
+    let mut line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut line)
+        .map_err(|e| format!("cannot read the response: {}", e))?;
+    serde_json::from_str(&line).map_err(|e| format!("invalid response: {}", e))
+}
+
+#[cfg(not(unix))]
+fn send(_socket: &str, _request: &Value) -> Result<Value, String> {
+    Err("control sockets are only supported on Unix".to_string())
+}
+
+fn print_result(request: &Value, result: &Value) {
+    match request["command"].as_str().unwrap_or_default() {
+        "list" | "reload-config" => print_tasks(result.as_array().map(Vec::as_slice).unwrap_or_default()),
+        "status" if request["task"].is_null() => print_health(result),
+        "status" => print_task(result),
+        "drain" => match result["running"].as_array() {
+            Some(running) if !running.is_empty() => {
+                println!("Timed out, still running: {}", join(running));
+            }
+            _ => println!("Drained, all tasks paused and none running"),
+        },
+        _ => println!("{}: {}", text(&result["name"]), text(&result["state"])),
+    }
+}
+
+fn print_tasks(tasks: &[Value]) {
+    let rows: Vec<[String; 5]> = tasks
+        .iter()
+        .map(|task| {
+            [
+                text(&task["name"]),
+                text(&task["state"]),
+                text(&task["schedule"]),
+                text(&task["next_fire"]),
+                match task["last_outcome"].as_str() {
+                    Some(outcome) => format!("{} at {}", outcome, text(&task["last_started"])),
+                    None => String::new(),
+                },
+            ]
+        })
+        .collect();
+
+    let header = ["TASK", "STATE", "SCHEDULE", "NEXT FIRE", "LAST RUN"].map(String::from);
+    let mut widths = header.clone().map(|title| title.len());
+    for row in &rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.len());
+        }
+    }
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row.iter().zip(widths).map(|(value, width)| format!("{:width$}", value)).collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+}
+
+fn print_task(task: &Value) {
+    println!("{}", text(&task["name"]));
+    let fields = [
+        ("state", text(&task["state"])),
+        ("kind", text(&task["kind"])),
+        ("schedule", text(&task["schedule"])),
+        ("next fire", text(&task["next_fire"])),
+        ("last success", text(&task["last_success"])),
+        ("runs", format!("{} ok, {} failed", task["success_count"], task["failure_count"])),
+    ];
+    for (label, value) in fields {
+        println!("  {:<13}{}", label, value);
+    }
+    if task["overdue"] == json!(true) {
+        println!("  overdue");
+    }
+
+    let history = task["history"].as_array().map(Vec::as_slice).unwrap_or_default();
+    if !history.is_empty() {
+        println!("  last runs:");
+    }
+    for run in history.iter().rev() {
+        let error = run["error"].as_str().map(|error| format!(" ({})", error)).unwrap_or_default();
+        println!(
+            "    {}  {:>7} ms  {}{}",
+            text(&run["started_at"]),
+            run["duration_ms"],
+            text(&run["outcome"]),
+            error
+        );
+    }
+}
+
+fn print_health(health: &Value) {
+    println!("{}", text(&health["status"]));
+    if health["cron_engine_alive"] == json!(false) {
+        println!("  cron engine not alive");
+    }
+    let sections = [
+        ("missed fire", "missed_fires"),
+        ("dead loop", "dead_loops"),
+        ("failing", "failing"),
+        ("stuck", "stuck"),
+        ("overdue", "overdue"),
+    ];
+    for (label, key) in sections {
+        if let Some(tasks) = health[key].as_array().filter(|tasks| !tasks.is_empty()) {
+            println!("  {}: {}", label, join(tasks));
+        }
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(value) => value.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn join(values: &[Value]) -> String {
+    values.iter().map(text).collect::<Vec<_>>().join(", ")
+}
@@ -1,6 +1,5 @@
 use concerto::{scheduled, SchedulerBuilder};
 use chrono::Local;
-use tracing_subscriber;
 
 /// Task with cron expression - runs every minute
 #[scheduled(cron = "* * * * * *")]
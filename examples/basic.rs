@@ -1,7 +1,6 @@
 use concerto::{scheduled, SchedulerBuilder};
 use std::sync::atomic::{AtomicU32, Ordering};
 use chrono::Local;
-use tracing_subscriber;
 
 static COUNTER: AtomicU32 = AtomicU32::new(0);
 
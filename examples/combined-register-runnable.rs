@@ -95,9 +95,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Build scheduler with UNIFIED .register() for BOTH approaches
     let scheduler = SchedulerBuilder::new()
-        // PART 1: Register instance with #[scheduled] methods
-        .register(user_service)
-        .register(user_service_staging)
+        // PART 1: Register instances with #[scheduled] methods
+        // Two instances of the same type need distinct names ("production::sync_users", ...)
+        .register_named(user_service, "production")
+        .register_named(user_service_staging, "staging")
         // PART 2: Register Runnable trait implementations (now also works with .register()!)
         .register(db_backup)
         .register(email_sender)
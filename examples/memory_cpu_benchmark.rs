@@ -190,10 +190,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut builder = SchedulerBuilder::new();
     
     // Register 10 runnable tasks
-    for _ in 0..10 {
-        builder = builder.register(BenchmarkTask {
+    for i in 0..10 {
+        builder = builder.register_named(BenchmarkTask {
             counter: Arc::clone(&task_counter),
-        });
+        }, &format!("benchmark_{}", i));
     }
     
     let scheduler = builder.build();
@@ -219,25 +219,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut builder = SchedulerBuilder::new();
     
     // Register 20 high-frequency tasks
-    for _ in 0..20 {
-        builder = builder.register(HighFrequencyTask {
+    for i in 0..20 {
+        builder = builder.register_named(HighFrequencyTask {
             counter: Arc::clone(&high_freq_counter),
-        });
+        }, &format!("high_frequency_{}", i));
     }
     
     // Register 10 CPU-intensive tasks
-    for _ in 0..10 {
-        builder = builder.register(CpuIntensiveTask {
+    for i in 0..10 {
+        builder = builder.register_named(CpuIntensiveTask {
             counter: Arc::clone(&cpu_counter),
-        });
+        }, &format!("cpu_intensive_{}", i));
     }
     
     // Register 5 memory-intensive tasks
-    for _ in 0..5 {
-        builder = builder.register(MemoryIntensiveTask {
+    for i in 0..5 {
+        builder = builder.register_named(MemoryIntensiveTask {
             counter: Arc::clone(&mem_counter),
             data: (0..1000).collect(),
-        });
+        }, &format!("memory_intensive_{}", i));
     }
     
     let scheduler = builder.build();